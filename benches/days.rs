@@ -0,0 +1,39 @@
+//! Benchmarks for every day, measuring the parsing separately from each solver
+//!
+//! Run them with `cargo bench`, or only a single day with `cargo bench -- y2021/day05`.
+
+use advent_of_code_2021::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmark one day on its real puzzle input
+///
+/// All solvers of a day share the same parsed input, so parsing is only benchmarked once.
+fn bench_day(c: &mut Criterion, solution: &'static dyn DynSolution) {
+    let solvers = solution.solvers();
+    let input = std::fs::read_to_string(solvers[0].input_path()).unwrap();
+    let mut group = c.benchmark_group(format!("y{}/day{:02}", solution.year(), solution.day()));
+
+    group.bench_function("generator", |b| {
+        b.iter_with_large_drop(|| solvers[0].parse(black_box(&input)).unwrap())
+    });
+
+    for solver in &solvers {
+        let parsed = solver.parse(&input).unwrap();
+        let name = match solver.variant {
+            Some(variant) => format!("part{}_{}", solver.part, variant),
+            None => format!("part{}", solver.part),
+        };
+        group.bench_function(name, |b| b.iter(|| black_box(parsed.solve().unwrap())));
+    }
+
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    for solution in YEARS.iter().flat_map(|solutions| solutions.iter()) {
+        bench_day(c, *solution);
+    }
+}
+
+criterion_group!(all_days, benches);
+criterion_main!(all_days);
@@ -0,0 +1,79 @@
+//! Extract the example blocks from the puzzle descriptions in the module docs of each day
+//!
+//! The ```` ```text ```` blocks of `src/yYYYY/dayNN.rs` become `EXAMPLE_1`, `EXAMPLE_2`, … in `$OUT_DIR/examples/yYYYY/dayNN.rs`.
+//! A day includes them with `include!(concat!(env!("OUT_DIR"), "/examples/yYYYY/dayNN.rs"));`, such that its tests use exactly the examples shown in its docs.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+fn main() {
+    // Watch all of `src`, such that the days of a new year are picked up as well
+    println!("cargo:rerun-if-changed=src");
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+
+    for year_entry in std::fs::read_dir("src").unwrap() {
+        let year_dir = year_entry.unwrap().path();
+        let year = year_dir.file_name().unwrap().to_str().unwrap();
+        if !year_dir.is_dir() || !is_year(year) {
+            continue;
+        }
+        let examples_dir = Path::new(&out_dir).join("examples").join(year);
+        std::fs::create_dir_all(&examples_dir).unwrap();
+
+        for entry in std::fs::read_dir(&year_dir).unwrap() {
+            write_examples(&entry.unwrap().path(), &examples_dir);
+        }
+    }
+}
+
+/// Whether `name` is the module of a year, like `y2021`
+fn is_year(name: &str) -> bool {
+    name.len() == 5 && name.starts_with('y') && name[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Write the example constants of the day at `path` into `examples_dir`
+fn write_examples(path: &Path, examples_dir: &Path) {
+    let file_name = path.file_name().unwrap().to_str().unwrap();
+    if !file_name.starts_with("day") || !file_name.ends_with(".rs") {
+        return;
+    }
+
+    let mut generated = String::new();
+    for (i, example) in examples(&std::fs::read_to_string(path).unwrap())
+        .iter()
+        .enumerate()
+    {
+        writeln!(
+            generated,
+            "/// Example block {} of the puzzle description\n#[cfg(test)]\n#[allow(dead_code)]\nconst EXAMPLE_{}: &str = {:?};",
+            i + 1,
+            i + 1,
+            example
+        )
+        .unwrap();
+    }
+    std::fs::write(examples_dir.join(file_name), generated).unwrap();
+}
+
+/// Contents of the ```` ```text ```` blocks in the module docs, in order and without a trailing newline
+fn examples(source: &str) -> Vec<String> {
+    let mut examples = Vec::new();
+    let mut example: Option<Vec<&str>> = None;
+    for line in source
+        .lines()
+        .take_while(|line| line.starts_with("//!"))
+        .map(|line| line.trim_start_matches("//!"))
+    {
+        let line = line.strip_prefix(' ').unwrap_or(line);
+        match (&mut example, line) {
+            (None, "```text") => example = Some(Vec::new()),
+            (Some(lines), "```") => {
+                examples.push(lines.join("\n"));
+                example = None;
+            }
+            (Some(lines), line) => lines.push(line),
+            (None, _) => {}
+        }
+    }
+    examples
+}
@@ -0,0 +1,10 @@
+#![no_main]
+use advent_of_code_2021::y2021::day13;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        // Parsing may fail, but must never panic
+        let _ = day13::parse(input);
+    }
+});
@@ -0,0 +1,518 @@
+//! Create the skeleton for a new day
+//!
+//! `cargo run --bin scaffold -- <day> [<year>]` copies `src/template_day.rs` to `src/yYYYY/dayNN.rs`,
+//! creates an empty `input/YYYY/dayN.txt` and registers the module and its cargo feature.
+//! The year defaults to the latest one in `src`. The first day of a new year also creates the module of the year.
+
+use anyhow::{anyhow, ensure, Context as _, Result};
+use std::fs;
+use std::path::Path;
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let day = args
+        .next()
+        .ok_or_else(|| anyhow!("Usage: scaffold <day> [<year>]"))?;
+    let day: u32 = day
+        .parse()
+        .with_context(|| format!("Invalid day {:?}", day))?;
+    ensure!((1..=25).contains(&day), "Day must be between 1 and 25");
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let year = match args.next() {
+        Some(year) => year
+            .parse()
+            .with_context(|| format!("Invalid year {:?}", year))?,
+        None => latest_year(&root.join("src"))?,
+    };
+    ensure!(
+        (2015..=9999).contains(&year),
+        "Year must be between 2015 and 9999"
+    );
+
+    let module_path = root.join(format!("src/y{}/day{:02}.rs", year, day));
+    ensure!(
+        !module_path.exists(),
+        "{} already exists",
+        module_path.display()
+    );
+
+    let year_path = root.join(format!("src/y{}.rs", year));
+    let lib_path = root.join("src/lib.rs");
+    let template = fs::read_to_string(root.join("src/template_day.rs"))?;
+    let manifest = fs::read_to_string(root.join("Cargo.toml"))?;
+    // Compute all changes first, such that nothing is written if any of them fails
+    let module = render_template(&template, year, day)?;
+    let (year_module, lib) = if year_path.exists() {
+        (
+            add_module(&fs::read_to_string(&year_path)?, year, day)?,
+            None,
+        )
+    } else {
+        let lib = add_year(&fs::read_to_string(&lib_path)?, year)?;
+        (new_year_module(year, day), Some(lib))
+    };
+    let manifest = add_feature(&manifest, year, day)?;
+
+    fs::create_dir_all(module_path.parent().unwrap())?;
+    fs::write(&module_path, module)?;
+    println!("Created {}", module_path.display());
+    let input_path = root.join(format!("input/{}/day{}.txt", year, day));
+    if !input_path.exists() {
+        fs::create_dir_all(input_path.parent().unwrap())?;
+        fs::write(&input_path, "")?;
+        println!("Created {}", input_path.display());
+    }
+    fs::write(&year_path, year_module)?;
+    if let Some(lib) = lib {
+        fs::write(&lib_path, lib)?;
+        println!("Registered module and feature y{}", year);
+    }
+    fs::write(root.join("Cargo.toml"), manifest)?;
+    println!("Registered module and feature y{}-day{:02}", year, day);
+    Ok(())
+}
+
+/// Latest year with a module `yYYYY.rs` in `src`
+fn latest_year(src: &Path) -> Result<u32> {
+    let mut latest = None;
+    for entry in fs::read_dir(src)? {
+        let file_name = entry?.file_name();
+        let year = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix('y')?.strip_suffix(".rs")?.parse().ok());
+        latest = latest.max(year);
+    }
+    latest.ok_or_else(|| anyhow!("Cannot find the module of any year in {}", src.display()))
+}
+
+/// Replace the year and day placeholders in the template, which is written for day 1 of 2021
+fn render_template(template: &str, year: u32, day: u32) -> Result<String> {
+    // The day only appears in the `Solution` of the day
+    ensure!(
+        template.contains("pub struct Day01;")
+            && template.contains("const YEAR: u32 = 2021;")
+            && template.contains("const DAY: u32 = 1;"),
+        "The template does not contain the solution for day 1 of 2021"
+    );
+    Ok(template
+        .replace("Day01", &format!("Day{:02}", day))
+        .replace(
+            "const YEAR: u32 = 2021;",
+            &format!("const YEAR: u32 = {};", year),
+        )
+        .replace("const DAY: u32 = 1;", &format!("const DAY: u32 = {};", day))
+        .replace("/// Day 1:", &format!("/// Day {}:", day)))
+}
+
+/// Module of a new year, with only the given day
+fn new_year_module(year: u32, day: u32) -> String {
+    format!(
+        r#"//! Solvers for each day of the {year} calendar
+//!
+//! Each day implements [`Solution`](crate::Solution) and is listed in [`SOLUTIONS`].
+//! Only the days enabled through their cargo feature, e.g., `y{year}-day{day:02}`, are available.
+
+#[cfg(feature = "y{year}-day{day:02}")]
+pub mod day{day:02};
+
+/// All days of {year} enabled through their cargo feature, in calendar order
+pub static SOLUTIONS: &[&dyn crate::DynSolution] = &[
+    #[cfg(feature = "y{year}-day{day:02}")]
+    &day{day:02}::Day{day:02},
+];
+"#,
+        year = year,
+        day = day
+    )
+}
+
+/// Add the feature gated `mod` declaration and the entry in `SOLUTIONS` to the module of the year, keeping the days sorted
+fn add_module(year_module: &str, year: u32, day: u32) -> Result<String> {
+    let declaration = format!("pub mod day{:02};", day);
+    ensure!(
+        !year_module.lines().any(|line| line == declaration),
+        "y{}.rs already contains day{:02}",
+        year,
+        day
+    );
+
+    let mut lines: Vec<&str> = year_module.lines().collect();
+    // Insert after the last module for an earlier day or before the first module
+    let idx = match lines.iter().rposition(
+        |line| matches!(parse_number(line, "pub mod day", ";"), Some(other) if other < day),
+    ) {
+        Some(idx) => idx + 1,
+        None => lines
+            .iter()
+            .position(|line| line.starts_with("#[cfg(feature = \"y"))
+            .ok_or_else(|| anyhow!("Cannot find the module declarations in y{}.rs", year))?,
+    };
+    let feature = format!("#[cfg(feature = \"y{}-day{:02}\")]", year, day);
+    lines.insert(idx, &declaration);
+    lines.insert(idx, &feature);
+
+    // Insert the entry after the one for an earlier day or at the start of `SOLUTIONS`
+    let entry = format!("    &day{:02}::Day{:02},", day, day);
+    let entry_feature = format!("    {}", feature);
+    let entry_day = |line: &str| -> Option<u32> {
+        line.strip_prefix("    &day")?
+            .split_once("::")?
+            .0
+            .parse()
+            .ok()
+    };
+    let idx = match lines
+        .iter()
+        .rposition(|line| matches!(entry_day(line), Some(other) if other < day))
+    {
+        Some(idx) => idx + 1,
+        None => {
+            lines
+                .iter()
+                .position(|line| line.starts_with("pub static SOLUTIONS"))
+                .ok_or_else(|| anyhow!("Cannot find SOLUTIONS in y{}.rs", year))?
+                + 1
+        }
+    };
+    lines.insert(idx, &entry);
+    lines.insert(idx, &entry_feature);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Declare the module of a new year in `lib.rs` and add its solutions to `YEARS`
+fn add_year(lib: &str, year: u32) -> Result<String> {
+    let declaration = format!("pub mod y{};", year);
+    ensure!(
+        !lib.lines().any(|line| line == declaration),
+        "lib.rs already contains y{}",
+        year
+    );
+
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+    let is_year_module = |line: &str| parse_number(line, "pub mod y", ";").is_some();
+    let idx = lines
+        .iter()
+        .rposition(|line| is_year_module(line) && *line < declaration)
+        .map(|idx| idx + 1)
+        .or_else(|| lines.iter().position(|line| is_year_module(line)))
+        .ok_or_else(|| anyhow!("Cannot find the modules of the years in lib.rs"))?;
+    lines.insert(idx, declaration);
+
+    // `YEARS` fits on a single line, as formatted by rustfmt
+    let years_line = lines
+        .iter_mut()
+        .find(|line| line.starts_with("pub static YEARS"))
+        .ok_or_else(|| anyhow!("Cannot find YEARS in lib.rs"))?;
+    let (head, entries) = years_line
+        .rsplit_once(" = &[")
+        .and_then(|(head, entries)| Some((head, entries.strip_suffix("];")?)))
+        .ok_or_else(|| anyhow!("YEARS in lib.rs is not a single line"))?;
+    let mut entries: Vec<String> = entries.split(", ").map(String::from).collect();
+    entries.push(format!("y{}::SOLUTIONS", year));
+    entries.sort();
+    *years_line = format!("{} = &[{}];", head, entries.join(", "));
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add the day feature to `Cargo.toml` and enable it as part of its year, creating the feature of a new year
+fn add_feature(manifest: &str, year: u32, day: u32) -> Result<String> {
+    let year_feature = format!("y{}", year);
+    let day_feature = format!("y{}-day{:02}", year, day);
+    let definition = format!("{} = []", day_feature);
+    ensure!(
+        !manifest.lines().any(|line| line == definition),
+        "Cargo.toml already contains the feature {}",
+        day_feature
+    );
+
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+    let year_start = format!("{} = [", year_feature);
+    if !lines.contains(&year_start) {
+        insert_sorted(&mut lines, "all-days = [", &year_feature)?;
+        // Keep the years sorted, otherwise append the new year after the features of the others
+        let is_year_start = |line: &str| parse_number(line, "y", " = [").is_some();
+        let idx = match lines
+            .iter()
+            .position(|line| is_year_start(line) && *line > year_start)
+        {
+            Some(idx) => idx,
+            None => {
+                lines
+                    .iter()
+                    .rposition(|line| line.starts_with('y') && line.ends_with(" = []"))
+                    .ok_or_else(|| anyhow!("Cannot find the day features in Cargo.toml"))?
+                    + 1
+            }
+        };
+        lines.insert(idx, "]".to_string());
+        lines.insert(idx, year_start.clone());
+    }
+    insert_sorted(&mut lines, &year_start, &day_feature)?;
+
+    // Insert the definition after an earlier day of the year or right after the list of the year
+    let day_prefix = format!("{}-day", year_feature);
+    let idx = match lines.iter().rposition(
+        |line| matches!(parse_number(line, &day_prefix, " = []"), Some(other) if other < day),
+    ) {
+        Some(idx) => idx,
+        None => list_end(&lines, &year_start)?,
+    };
+    lines.insert(idx + 1, definition);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add `"{entry}",` to the multi-line list starting with the line `start`, keeping it sorted
+fn insert_sorted(lines: &mut Vec<String>, start: &str, entry: &str) -> Result<()> {
+    let entry = format!("    \"{}\",", entry);
+    let start_idx = lines
+        .iter()
+        .position(|line| line == start)
+        .ok_or_else(|| anyhow!("Cannot find {:?} in Cargo.toml", start))?;
+    let end_idx = list_end(lines, start)?;
+    let idx = lines[start_idx + 1..end_idx]
+        .iter()
+        .rposition(|line| *line < entry)
+        .map_or(start_idx, |idx| start_idx + 1 + idx);
+    lines.insert(idx + 1, entry);
+    Ok(())
+}
+
+/// Index of the line closing the multi-line list starting with the line `start`
+fn list_end(lines: &[String], start: &str) -> Result<usize> {
+    let start_idx = lines
+        .iter()
+        .position(|line| line == start)
+        .ok_or_else(|| anyhow!("Cannot find {:?} in Cargo.toml", start))?;
+    lines[start_idx..]
+        .iter()
+        .position(|line| line == "]")
+        .map(|idx| start_idx + idx)
+        .ok_or_else(|| anyhow!("The list {:?} in Cargo.toml is not terminated", start))
+}
+
+/// Extract the number from `line` if it has the form `{prefix}NN{suffix}`
+fn parse_number(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    line.strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+static TEST_YEAR: &str = r#"//! Solvers for each day of the 2021 calendar
+
+#[cfg(feature = "y2021-day01")]
+pub mod day01;
+#[cfg(feature = "y2021-day03")]
+pub mod day03;
+
+pub static SOLUTIONS: &[&dyn crate::DynSolution] = &[
+    #[cfg(feature = "y2021-day01")]
+    &day01::Day01,
+    #[cfg(feature = "y2021-day03")]
+    &day03::Day03,
+];
+"#;
+
+#[cfg(test)]
+static TEST_LIB: &str = r#"mod prelude;
+
+pub mod y2021;
+
+pub static YEARS: &[&[&dyn DynSolution]] = &[y2021::SOLUTIONS];
+"#;
+
+#[cfg(test)]
+static TEST_MANIFEST: &str = r#"[features]
+default = ["all-days"]
+all-days = [
+    "y2021",
+]
+y2021 = [
+    "y2021-day01",
+    "y2021-day03",
+]
+y2021-day01 = []
+y2021-day03 = []
+
+[dependencies]
+"#;
+
+#[test]
+fn test_render_template() -> Result<()> {
+    let template = "/// Day 1: TODO\npub struct Day01;\n\nimpl Solution for Day01 {\n    const YEAR: u32 = 2021;\n    const DAY: u32 = 1;\n}\n";
+    assert_eq!(
+        "/// Day 12: TODO\npub struct Day12;\n\nimpl Solution for Day12 {\n    const YEAR: u32 = 2022;\n    const DAY: u32 = 12;\n}\n",
+        render_template(template, 2022, 12)?
+    );
+    assert!(render_template("pub struct Day1;", 2021, 12).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_add_module() -> Result<()> {
+    assert_eq!(
+        r#"//! Solvers for each day of the 2021 calendar
+
+#[cfg(feature = "y2021-day01")]
+pub mod day01;
+#[cfg(feature = "y2021-day02")]
+pub mod day02;
+#[cfg(feature = "y2021-day03")]
+pub mod day03;
+
+pub static SOLUTIONS: &[&dyn crate::DynSolution] = &[
+    #[cfg(feature = "y2021-day01")]
+    &day01::Day01,
+    #[cfg(feature = "y2021-day02")]
+    &day02::Day02,
+    #[cfg(feature = "y2021-day03")]
+    &day03::Day03,
+];
+"#,
+        add_module(TEST_YEAR, 2021, 2)?
+    );
+    assert_eq!(
+        r#"//! Solvers for each day of the 2021 calendar
+
+#[cfg(feature = "y2021-day01")]
+pub mod day01;
+#[cfg(feature = "y2021-day03")]
+pub mod day03;
+#[cfg(feature = "y2021-day19")]
+pub mod day19;
+
+pub static SOLUTIONS: &[&dyn crate::DynSolution] = &[
+    #[cfg(feature = "y2021-day01")]
+    &day01::Day01,
+    #[cfg(feature = "y2021-day03")]
+    &day03::Day03,
+    #[cfg(feature = "y2021-day19")]
+    &day19::Day19,
+];
+"#,
+        add_module(TEST_YEAR, 2021, 19)?
+    );
+    assert!(add_module(TEST_YEAR, 2021, 3).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_add_year() -> Result<()> {
+    assert_eq!(
+        r#"mod prelude;
+
+pub mod y2021;
+pub mod y2022;
+
+pub static YEARS: &[&[&dyn DynSolution]] = &[y2021::SOLUTIONS, y2022::SOLUTIONS];
+"#,
+        add_year(TEST_LIB, 2022)?
+    );
+    assert_eq!(
+        r#"mod prelude;
+
+pub mod y2020;
+pub mod y2021;
+
+pub static YEARS: &[&[&dyn DynSolution]] = &[y2020::SOLUTIONS, y2021::SOLUTIONS];
+"#,
+        add_year(TEST_LIB, 2020)?
+    );
+    assert!(add_year(TEST_LIB, 2021).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_add_feature() -> Result<()> {
+    assert_eq!(
+        r#"[features]
+default = ["all-days"]
+all-days = [
+    "y2021",
+]
+y2021 = [
+    "y2021-day01",
+    "y2021-day02",
+    "y2021-day03",
+]
+y2021-day01 = []
+y2021-day02 = []
+y2021-day03 = []
+
+[dependencies]
+"#,
+        add_feature(TEST_MANIFEST, 2021, 2)?
+    );
+    assert_eq!(
+        r#"[features]
+default = ["all-days"]
+all-days = [
+    "y2021",
+]
+y2021 = [
+    "y2021-day01",
+    "y2021-day03",
+    "y2021-day19",
+]
+y2021-day01 = []
+y2021-day03 = []
+y2021-day19 = []
+
+[dependencies]
+"#,
+        add_feature(TEST_MANIFEST, 2021, 19)?
+    );
+    assert!(add_feature(TEST_MANIFEST, 2021, 1).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_add_feature_of_new_year() -> Result<()> {
+    assert_eq!(
+        r#"[features]
+default = ["all-days"]
+all-days = [
+    "y2021",
+    "y2022",
+]
+y2021 = [
+    "y2021-day01",
+    "y2021-day03",
+]
+y2021-day01 = []
+y2021-day03 = []
+y2022 = [
+    "y2022-day05",
+]
+y2022-day05 = []
+
+[dependencies]
+"#,
+        add_feature(TEST_MANIFEST, 2022, 5)?
+    );
+    assert_eq!(
+        r#"[features]
+default = ["all-days"]
+all-days = [
+    "y2020",
+    "y2021",
+]
+y2020 = [
+    "y2020-day01",
+]
+y2020-day01 = []
+y2021 = [
+    "y2021-day01",
+    "y2021-day03",
+]
+y2021-day01 = []
+y2021-day03 = []
+
+[dependencies]
+"#,
+        add_feature(TEST_MANIFEST, 2020, 1)?
+    );
+    Ok(())
+}
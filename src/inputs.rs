@@ -0,0 +1,34 @@
+//! Puzzle inputs, from downloading them to the canonical form the parsers see
+//!
+//! The inputs live in `input/<year>/day<day>.txt` in the crate. [`Fetcher`] downloads missing ones,
+//! and [`NormalizedInput`] smooths over the differences that editors introduce.
+
+mod fetch;
+mod normalized;
+
+pub use fetch::{FetchError, Fetcher};
+pub use normalized::NormalizedInput;
+
+use std::path::{Path, PathBuf};
+
+/// Directory of the official puzzle inputs in the crate
+pub fn input_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+}
+
+/// Path of the input of a day in `input_dir`, like `2021/day7.txt`
+pub fn day_path(input_dir: &Path, year: u32, day: u32) -> PathBuf {
+    input_dir
+        .join(year.to_string())
+        .join(format!("day{}.txt", day))
+}
+
+/// `input` as saved by a Windows editor, with a byte order mark, `\r\n` line endings and trailing spaces
+///
+/// The days check with it that their parsers see the same input after [normalizing](NormalizedInput) it.
+#[cfg(test)]
+// Unused if only days without such a test are enabled
+#[allow(dead_code)]
+pub(crate) fn windows_style(input: &str) -> String {
+    format!("\u{feff}{} \r\n", input.replace('\n', " \r\n"))
+}
@@ -0,0 +1,189 @@
+//! Download of the puzzle inputs, which differ per account and need its session token
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Environment variable with the session token, i.e., the value of the `session` cookie of adventofcode.com
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// File in the home directory with the session token, as also used by other Advent of Code tools
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// Environment variable to fetch from another server, e.g., a local stand-in for tests
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Error while fetching a puzzle input
+#[derive(Debug)]
+pub enum FetchError {
+    /// Neither the environment variable nor the file contain a session token
+    MissingToken,
+    /// The server refused to answer, and asks to wait for `retry_after` if it says how long
+    RateLimited { retry_after: Option<Duration> },
+    /// The server answered with another error, e.g., 400 for an expired token or 404 for a day that is not unlocked yet
+    Status {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    /// The server could not be reached
+    Transport { url: String, message: String },
+    /// The input could not be read from or written to the cache
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::MissingToken => write!(
+                f,
+                "No session token to fetch the input: set {} or save it in ~/{}",
+                SESSION_VAR, SESSION_FILE
+            ),
+            FetchError::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(
+                f,
+                "Rate limited by the server, retry in {} seconds",
+                retry_after.as_secs()
+            ),
+            FetchError::RateLimited { retry_after: None } => {
+                write!(f, "Rate limited by the server, retry later")
+            }
+            FetchError::Status { url, status, body } => {
+                write!(
+                    f,
+                    "Fetching {} failed with status {}: {}",
+                    url, status, body
+                )
+            }
+            FetchError::Transport { url, message } => {
+                write!(f, "Fetching {} failed: {}", url, message)
+            }
+            FetchError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Downloads missing puzzle inputs into a cache directory
+///
+/// Inputs already in the cache are never fetched again, which keeps the load on the server low.
+/// Empty files, like the ones created by the scaffold, do not count as cached.
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    input_dir: PathBuf,
+}
+
+impl Fetcher {
+    /// Fetcher for `base_url`, which caches the inputs in `input_dir` like [`input_dir`](super::input_dir)
+    pub fn new(
+        base_url: impl Into<String>,
+        session: Option<String>,
+        input_dir: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session,
+            input_dir: input_dir.into(),
+        }
+    }
+
+    /// Fetcher for the inputs of the crate, configured through [`BASE_URL_VAR`] and [`SESSION_VAR`] or [`SESSION_FILE`]
+    pub fn from_env() -> Self {
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = std::env::var(SESSION_VAR).ok().or_else(|| {
+            let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"))?;
+            std::fs::read_to_string(Path::new(&home).join(SESSION_FILE)).ok()
+        });
+        let session = session
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty());
+        Self::new(base_url, session, super::input_dir())
+    }
+
+    /// Path of the input in the cache
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        super::day_path(&self.input_dir, year, day)
+    }
+
+    /// Whether the input is in the cache, such that [`fetch`](Self::fetch) does not download it
+    pub fn is_cached(&self, year: u32, day: u32) -> bool {
+        std::fs::metadata(self.path(year, day)).map_or(false, |metadata| metadata.len() > 0)
+    }
+
+    /// Path of the input in the cache, after downloading it unless it is already there
+    pub fn fetch(&self, year: u32, day: u32) -> Result<PathBuf, FetchError> {
+        let path = self.path(year, day);
+        if self.is_cached(year, day) {
+            return Ok(path);
+        }
+        let session = self.session.as_deref().ok_or(FetchError::MissingToken)?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        log::info!("Fetching {}", url);
+        let agent = ureq::AgentBuilder::new()
+            .timeout(Duration::from_secs(30))
+            .user_agent(concat!(env!("CARGO_PKG_NAME"), " input fetcher"))
+            .build();
+        let input = match agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+        {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| FetchError::Transport {
+                    url: url.clone(),
+                    message: err.to_string(),
+                })?,
+            Err(ureq::Error::Status(429, response)) => {
+                return Err(FetchError::RateLimited {
+                    retry_after: response
+                        .header("Retry-After")
+                        .and_then(|seconds| seconds.trim().parse().ok())
+                        .map(Duration::from_secs),
+                })
+            }
+            Err(ureq::Error::Status(status, response)) => {
+                return Err(FetchError::Status {
+                    url,
+                    status,
+                    body: response
+                        .into_string()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string(),
+                })
+            }
+            Err(ureq::Error::Transport(transport)) => {
+                return Err(FetchError::Transport {
+                    url,
+                    message: transport.to_string(),
+                })
+            }
+        };
+
+        // Write to a temporary file first, such that an interrupted write never looks like a cached input
+        let io_error = |path: &Path| {
+            let path = path.to_path_buf();
+            move |error| FetchError::Io { path, error }
+        };
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir).map_err(io_error(dir))?;
+        let partial_path = path.with_extension("txt.partial");
+        std::fs::write(&partial_path, input).map_err(io_error(&partial_path))?;
+        std::fs::rename(&partial_path, &path).map_err(io_error(&path))?;
+        Ok(path)
+    }
+}
@@ -0,0 +1,164 @@
+//! Normalization of the puzzle inputs in front of the parsers of all days
+//!
+//! Inputs saved by other editors or on Windows differ from the downloaded ones in invisible ways,
+//! like `\r\n` line endings, a byte order mark or trailing spaces.
+//! The parsers only need to handle the canonical form, which [`NormalizedInput`] creates.
+
+use crate::ParseError;
+use std::borrow::Cow;
+
+/// Byte order mark, which some editors put at the start of UTF-8 files
+const BOM: char = '\u{feff}';
+
+/// Puzzle input with `\n` line endings, without a byte order mark and without trailing whitespace on each line and at the end
+///
+/// Byte offsets differ from the original input, but [`original_offset`](Self::original_offset) maps them back,
+/// and [`original_error`](Self::original_error) does the same for the position of a [`ParseError`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NormalizedInput<'a> {
+    original: &'a str,
+    text: Cow<'a, str>,
+    /// Byte offset of each line start in the normalized and in the original input, empty if nothing changed
+    line_starts: Vec<(usize, usize)>,
+}
+
+impl<'a> NormalizedInput<'a> {
+    pub fn new(original: &'a str) -> Self {
+        if is_normalized(original) {
+            return Self {
+                original,
+                text: Cow::Borrowed(original),
+                line_starts: Vec::new(),
+            };
+        }
+
+        let body = original.strip_prefix(BOM).unwrap_or(original);
+        let mut text = String::with_capacity(body.len());
+        let mut line_starts = Vec::new();
+        let mut original_start = original.len() - body.len();
+        for line in body.split('\n') {
+            line_starts.push((text.len(), original_start));
+            text.push_str(line.trim_end());
+            text.push('\n');
+            original_start += line.len() + 1;
+        }
+        text.truncate(text.trim_end().len());
+        Self {
+            original,
+            text: Cow::Owned(text),
+            line_starts,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Byte offset in the original input of the byte at `offset` in the normalized input
+    ///
+    /// Offsets behind the end of a line map to the end of its content in the original input.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        let line = self
+            .line_starts
+            .partition_point(|&(start, _)| start <= offset);
+        match line.checked_sub(1) {
+            Some(line) => {
+                let (start, original_start) = self.line_starts[line];
+                original_start + (offset - start)
+            }
+            None => offset,
+        }
+    }
+
+    /// Error of a parser of the normalized input, pointing at the same character in the original input
+    ///
+    /// The byte order mark does not count as a column, as editors do not show it.
+    pub fn original_error(&self, error: ParseError) -> ParseError {
+        let offset = match self.text.split('\n').nth(error.line - 1) {
+            Some(line) => {
+                let line_start = line.as_ptr() as usize - self.text.as_ptr() as usize;
+                line_start
+                    + line
+                        .char_indices()
+                        .nth(error.column - 1)
+                        .map_or(line.len(), |(idx, _)| idx)
+            }
+            None => self.text.len(),
+        };
+        let body = self.original.strip_prefix(BOM).unwrap_or(self.original);
+        let offset = self.original_offset(offset).min(self.original.len());
+        ParseError::at(
+            error.day,
+            body,
+            &self.original[offset.max(self.original.len() - body.len())..],
+            error.expected,
+        )
+    }
+}
+
+/// Whether normalizing `input` would not change it
+fn is_normalized(input: &str) -> bool {
+    !input.starts_with(BOM)
+        && !input.ends_with(char::is_whitespace)
+        && input
+            .split('\n')
+            .all(|line| !line.ends_with(char::is_whitespace))
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!("1\n2\n\n3", NormalizedInput::new("1\n2\n\n3").as_str());
+    assert_eq!(
+        "1\n2\n\n3",
+        NormalizedInput::new("\u{feff}1\r\n2  \r\n\t\r\n3\r\n\r\n").as_str()
+    );
+    assert_eq!("  1,2", NormalizedInput::new("  1,2 \n").as_str());
+    assert_eq!("", NormalizedInput::new("\u{feff}\r\n").as_str());
+}
+
+#[test]
+fn test_original_offset() {
+    let original = "\u{feff}ab \r\ncd\r\n";
+    let input = NormalizedInput::new(original);
+    assert_eq!("ab\ncd", input.as_str());
+    for (offset, c) in input.as_str().char_indices() {
+        if c != '\n' {
+            assert_eq!(
+                Some(c),
+                original[input.original_offset(offset)..].chars().next()
+            );
+        }
+    }
+    // The line break after `ab` maps to the end of its content
+    assert_eq!(5, input.original_offset(2));
+    assert_eq!(10, input.original_offset(5));
+
+    let input = NormalizedInput::new("ab\ncd");
+    assert_eq!(4, input.original_offset(4));
+}
+
+#[test]
+fn test_original_error() {
+    // Four trailing spaces and a tab on the first line move the second line in the original input
+    let original = "\u{feff}1,2    \t\r\n3,x\r\n";
+    let input = NormalizedInput::new(original);
+    assert_eq!("1,2\n3,x", input.as_str());
+    let error = ParseError::at(5, input.as_str(), &input.as_str()[6..], "a number");
+    assert_eq!(ParseError::new(5, 2, 3, "a number"), error);
+    assert_eq!(
+        ParseError::new(5, 2, 3, "a number"),
+        input.original_error(error)
+    );
+    // Behind the end of the normalized input is the end of the content of the last line
+    let error = ParseError::new(5, 3, 1, "another line");
+    assert_eq!(
+        ParseError::new(5, 2, 4, "another line"),
+        input.original_error(error)
+    );
+    // The byte order mark is invisible
+    let error = ParseError::new(5, 1, 2, "a digit");
+    assert_eq!(
+        ParseError::new(5, 1, 2, "a digit"),
+        input.original_error(error)
+    );
+}
@@ -5,24 +5,17 @@
 )]
 
 mod prelude;
+mod solution;
 
-// mod day01;
-// mod day02;
-// mod day03;
-// mod day04;
-// mod day05;
-// mod day06;
-// mod day07;
-// mod day08;
-// mod day09;
-// mod day10;
-// mod day11;
-// mod day12;
-// mod day13;
-// mod day14;
-// mod day15;
-// mod day16;
-// mod day17;
-mod day18;
+pub mod inputs;
+pub mod render;
+pub mod y2021;
 
-aoc_runner_derive::aoc_lib! { year = 2021 }
+pub use inputs::{FetchError, Fetcher, NormalizedInput};
+pub use prelude::{Grid, ParseError};
+pub use solution::{solvers, DynSolution, Parsed, Part, Solution, Solver};
+/// The days of 2021 under their path from before the split into years
+pub use y2021 as days;
+
+/// Solutions of every year, each in calendar order
+pub static YEARS: &[&[&dyn DynSolution]] = &[y2021::SOLUTIONS];
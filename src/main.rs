@@ -1 +1,587 @@
-aoc_runner_derive::aoc_main! { lib = advent_of_code_2021 }
+//! Run the solvers on their puzzle inputs and print the answers
+//!
+//! `--year`, `--day`, `--part` and `--variant` select the solvers, e.g., `--day 7 --variant naive`.
+//! Without `--year` only the latest year runs, unless `--all` runs the days of every year. Without `--day` or `--all` only the latest day runs.
+//! `--input <path>` reads the puzzle input from another file, or from stdin for `-`, instead of `input/<year>/dayN.txt`.
+//! `--all --parallel` runs all days concurrently on the rayon pool, then prints the CPU time of each day and the total wall time.
+//! `--format json` prints one record per solver with the answer, timings in nanoseconds, the SHA-256 hash of the input and the error if it failed.
+//! `--render <dir>` also writes a frame of each selected day that can be drawn, as `<year>-dayNN.txt` and a PGM or PPM image.
+//!
+//! `fetch` downloads the missing inputs of the selected days, see [`Fetcher::from_env`] for the session token and the server.
+//! `perf-baseline` saves the median timings of the selected solvers to `perf/baseline.json`, keeping the timings of the others.
+//! `perf-check` measures them again and fails with a table of the solvers that got slower than `--max-slowdown` times their baseline.
+//!
+//! Solvers log details about their work through the `log` facade, with one target per day, e.g., `advent_of_code_2021::y2021::day17`.
+//! Logging is off by default, such that only the answers are printed.
+//! Pass `--verbose` to see the debug output of all days or set `RUST_LOG`, e.g., `RUST_LOG=advent_of_code_2021::y2021::day15=trace`.
+
+use advent_of_code_2021::*;
+use anyhow::{bail, ensure, Context as _, Result};
+use clap::Parser;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use std::borrow::Cow;
+use std::io::Read as _;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Timings below this difference are noise rather than regressions, whatever their ratio
+const PERF_NOISE: Duration = Duration::from_micros(50);
+
+#[derive(Parser)]
+#[clap(about = "Solve the Advent of Code puzzles")]
+struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
+    /// Only run the solvers of this year, the latest year by default
+    #[clap(long, global = true)]
+    year: Option<u32>,
+    /// Only run the solvers of this day, the latest day by default
+    #[clap(long, global = true, conflicts_with = "all")]
+    day: Option<u32>,
+    /// Only run the solvers of this part
+    #[clap(long, global = true)]
+    part: Option<u32>,
+    /// Only run the solvers with this variant name, e.g., `naive`
+    #[clap(long, global = true)]
+    variant: Option<String>,
+    /// Read the puzzle input from this file, or from stdin for `-`
+    #[clap(long, global = true, conflicts_with = "all")]
+    input: Option<String>,
+    /// Run the solvers of all days, of every year unless `--year` is given
+    #[clap(long, global = true)]
+    all: bool,
+    /// Run the solvers of all days concurrently and print the total wall time
+    #[clap(long, requires = "all")]
+    parallel: bool,
+    /// Print the results as text or as JSON records
+    #[clap(long, arg_enum, default_value = "text")]
+    format: Format,
+    /// Show the debug output of the solvers
+    #[clap(short, long)]
+    verbose: bool,
+    /// Write ASCII art and an image of each selected day into this directory
+    #[clap(long)]
+    render: Option<PathBuf>,
+}
+
+#[derive(clap::ArgEnum, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Download the missing puzzle inputs of the selected days
+    Fetch,
+    /// Save the median timings of the selected solvers as their baseline
+    PerfBaseline(PerfArgs),
+    /// Compare the median timings of the selected solvers with their baseline
+    PerfCheck {
+        #[clap(flatten)]
+        perf: PerfArgs,
+        /// Fail when a solver takes more than this many times its baseline
+        #[clap(long, default_value = "2")]
+        max_slowdown: f64,
+    },
+}
+
+#[derive(clap::Args)]
+struct PerfArgs {
+    /// File with the baseline timings
+    #[clap(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/perf/baseline.json"))]
+    baseline: PathBuf,
+    /// Number of runs of each solver to take the median of
+    #[clap(long, default_value = "11")]
+    samples: usize,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let mut logger = env_logger::Builder::from_default_env();
+    if args.verbose {
+        logger.filter_module("advent_of_code_2021", log::LevelFilter::Debug);
+    }
+    logger.init();
+
+    let all_solvers = solvers();
+    let selected_year = match args.year {
+        Some(year) => Some(year),
+        None if args.all => None,
+        None => all_solvers.iter().map(|solver| solver.year).max(),
+    };
+    let selected_day = match args.day {
+        _ if args.all => None,
+        Some(day) => Some(day),
+        None => all_solvers
+            .iter()
+            .filter(|solver| selected_year == Some(solver.year))
+            .map(|solver| solver.day)
+            .max(),
+    };
+    let solvers: Vec<Solver> = all_solvers
+        .into_iter()
+        .filter(|solver| {
+            selected_year.map_or(true, |selected| selected == solver.year)
+                && selected_day.map_or(true, |selected| selected == solver.day)
+                && args.part.map_or(true, |selected| selected == solver.part)
+                && args
+                    .variant
+                    .as_deref()
+                    .map_or(true, |selected| solver.variant == Some(selected))
+        })
+        .collect();
+    ensure!(!solvers.is_empty(), "No solver matches the selection");
+
+    // Read the input only once, since stdin cannot be read again for the next part
+    let input = match args.input.as_deref() {
+        Some("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .context("Failed to read the input from stdin")?;
+            Some(input)
+        }
+        Some(path) => Some(
+            std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read the input from {}", path))?,
+        ),
+        None => None,
+    };
+
+    match &args.command {
+        None => {}
+        Some(Command::Fetch) => return fetch_inputs(&solvers),
+        Some(Command::PerfBaseline(perf)) => {
+            return perf_baseline(&solvers, input.as_deref(), perf);
+        }
+        Some(Command::PerfCheck { perf, max_slowdown }) => {
+            return perf_check(&solvers, input.as_deref(), perf, *max_slowdown);
+        }
+    }
+
+    let mut printed_year = None;
+    let start_time = Instant::now();
+    let records: Vec<Record> = if args.parallel {
+        // Collecting keeps the calendar order, but nothing can be printed before all days are done
+        let records: Vec<Record> = solvers
+            .par_iter()
+            .map(|solver| run_solver(solver, input.as_deref()))
+            .collect();
+        let wall_time = start_time.elapsed();
+        if args.format == Format::Text {
+            for record in &records {
+                record.print_text(&mut printed_year);
+            }
+            print_cpu_times(&records, wall_time);
+        }
+        records
+    } else {
+        solvers
+            .iter()
+            .map(|solver| {
+                let record = run_solver(solver, input.as_deref());
+                // Print the text right away, such that slow days do not hold back the others
+                if args.format == Format::Text {
+                    record.print_text(&mut printed_year);
+                }
+                record
+            })
+            .collect()
+    };
+    if args.format == Format::Json {
+        serde_json::to_writer_pretty(std::io::stdout().lock(), &records)?;
+        println!();
+    }
+    if let Some(dir) = &args.render {
+        render_frames(&solvers, input.as_deref(), dir)?;
+    }
+
+    let failures = records.iter().filter(|record| !record.success).count();
+    ensure!(failures == 0, "{} solvers failed", failures);
+    Ok(())
+}
+
+/// Result of running a single solver
+#[derive(Serialize)]
+struct Record {
+    #[serde(skip)]
+    solver: Solver,
+    year: u32,
+    day: u32,
+    part: u32,
+    variant: Option<&'static str>,
+    /// Answer as displayed by the solver, which is ASCII art for some days
+    answer: Option<String>,
+    /// Time spent in the input generator
+    #[serde(rename = "parse_time_ns", serialize_with = "serialize_nanos")]
+    parse_time: Option<Duration>,
+    /// Time spent in the solver
+    #[serde(rename = "solve_time_ns", serialize_with = "serialize_nanos")]
+    solve_time: Option<Duration>,
+    /// Hex encoded SHA-256 hash of the puzzle input
+    input_sha256: Option<String>,
+    success: bool,
+    error: Option<String>,
+}
+
+impl Record {
+    fn name(&self) -> String {
+        self.solver.name()
+    }
+
+    /// Print the record, preceded by a header if its year differs from the `printed_year` of the previous record
+    fn print_text(&self, printed_year: &mut Option<u32>) {
+        if *printed_year != Some(self.year) {
+            println!("Advent of code {}", self.year);
+            *printed_year = Some(self.year);
+        }
+        let name = self.name();
+        match (&self.answer, self.parse_time, self.solve_time, &self.error) {
+            (Some(answer), Some(parse_time), Some(solve_time), None) => println!(
+                "{}: {}\n\tgenerator: {:?},\n\trunner: {:?}\n",
+                name, answer, parse_time, solve_time
+            ),
+            (.., error) => eprintln!(
+                "{}: FAILED {}\n",
+                name,
+                error.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+/// Print the time spent in the solvers of each day, which may add up to more than the wall time
+fn print_cpu_times(records: &[Record], wall_time: Duration) {
+    let mut cpu_times = std::collections::BTreeMap::new();
+    for record in records {
+        *cpu_times
+            .entry((record.year, record.day))
+            .or_insert(Duration::ZERO) +=
+            record.parse_time.unwrap_or_default() + record.solve_time.unwrap_or_default();
+    }
+    println!("CPU time per day:");
+    for ((year, day), cpu_time) in cpu_times {
+        println!("\t{} Day {}: {:?}", year, day, cpu_time);
+    }
+    println!("Total wall time: {:?}", wall_time);
+}
+
+fn serialize_nanos<S: serde::Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    duration
+        .map(|duration| duration.as_nanos() as u64)
+        .serialize(serializer)
+}
+
+/// Run the solver on `input`, or on the puzzle input of its day if there is none
+fn run_solver(solver: &Solver, input: Option<&str>) -> Record {
+    let mut record = Record {
+        solver: *solver,
+        year: solver.year,
+        day: solver.day,
+        part: solver.part,
+        variant: solver.variant,
+        answer: None,
+        parse_time: None,
+        solve_time: None,
+        input_sha256: None,
+        success: false,
+        error: None,
+    };
+
+    let input = match input {
+        Some(input) => Cow::Borrowed(input),
+        None => {
+            let path = solver.input_path();
+            match std::fs::read_to_string(&path) {
+                Ok(input) => Cow::Owned(input),
+                Err(err) => {
+                    record.error = Some(format!("while reading {}: {}", path.display(), err));
+                    return record;
+                }
+            }
+        }
+    };
+    record.input_sha256 = Some(format!("{:x}", Sha256::digest(input.as_bytes())));
+
+    let start_time = Instant::now();
+    let parsed = match solver.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            record.error = Some(format!("while generating: {}", err));
+            return record;
+        }
+    };
+    let inter_time = Instant::now();
+    let answer = parsed.solve();
+    record.parse_time = Some(inter_time - start_time);
+    record.solve_time = Some(inter_time.elapsed());
+    match answer {
+        Ok(answer) => {
+            record.answer = Some(answer.to_string());
+            record.success = true;
+        }
+        Err(err) => record.error = Some(format!("while running: {}", err)),
+    }
+    record
+}
+
+/// Download the inputs of the days of `solvers` which are not in `input/` yet
+fn fetch_inputs(solvers: &[Solver]) -> Result<()> {
+    let fetcher = Fetcher::from_env();
+    let days: std::collections::BTreeSet<(u32, u32)> = solvers
+        .iter()
+        .map(|solver| (solver.year, solver.day))
+        .collect();
+    for (year, day) in days {
+        if fetcher.is_cached(year, day) {
+            println!(
+                "{} Day {}: already in {}",
+                year,
+                day,
+                fetcher.path(year, day).display()
+            );
+            continue;
+        }
+        let path = fetcher
+            .fetch(year, day)
+            .with_context(|| format!("Failed to fetch the input of day {} of {}", day, year))?;
+        println!("{} Day {}: saved to {}", year, day, path.display());
+    }
+    Ok(())
+}
+
+/// Write the ASCII art and the image of each day of `solvers` into `dir`
+///
+/// Notes go to stderr, such that the JSON records stay the only output on stdout.
+fn render_frames(solvers: &[Solver], input: Option<&str>, dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let mut rendered = std::collections::BTreeSet::new();
+    for solver in solvers {
+        if !rendered.insert((solver.year, solver.day)) {
+            continue;
+        }
+        let input = match input {
+            Some(input) => Cow::Borrowed(input),
+            None => {
+                let path = solver.input_path();
+                Cow::Owned(
+                    std::fs::read_to_string(&path)
+                        .with_context(|| format!("Failed to read {}", path.display()))?,
+                )
+            }
+        };
+        let frame = match solver.render(&input)? {
+            Some(frame) => frame,
+            None => {
+                eprintln!("{} Day {}: nothing to render", solver.year, solver.day);
+                continue;
+            }
+        };
+
+        let (ascii, image) = match frame.ascii().and_then(|ascii| Ok((ascii, frame.image()?))) {
+            Ok(drawn) => drawn,
+            Err(err) => {
+                eprintln!("{} Day {}: skipped: {}", solver.year, solver.day, err);
+                continue;
+            }
+        };
+
+        let name = format!("{}-day{:02}", solver.year, solver.day);
+        let ascii_path = dir.join(format!("{}.txt", name));
+        std::fs::write(&ascii_path, ascii)
+            .with_context(|| format!("Failed to write {}", ascii_path.display()))?;
+        let image_path = dir.join(format!("{}.{}", name, image.extension()));
+        let mut file = std::io::BufWriter::new(
+            std::fs::File::create(&image_path)
+                .with_context(|| format!("Failed to create {}", image_path.display()))?,
+        );
+        image
+            .write_netpbm(&mut file)
+            .and_then(|()| std::io::Write::flush(&mut file))
+            .with_context(|| format!("Failed to write {}", image_path.display()))?;
+        eprintln!(
+            "{} Day {}: rendered to {} and {}",
+            solver.year,
+            solver.day,
+            ascii_path.display(),
+            image_path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Median timings of a solver, as stored in the baseline file
+#[derive(Serialize, Deserialize)]
+struct Timing {
+    year: u32,
+    day: u32,
+    part: u32,
+    variant: Option<String>,
+    /// Timings of another input cannot be compared
+    input_sha256: String,
+    parse_time_ns: u64,
+    solve_time_ns: u64,
+}
+
+impl Timing {
+    fn key(&self) -> (u32, u32, u32, Option<&str>) {
+        (self.year, self.day, self.part, self.variant.as_deref())
+    }
+}
+
+/// Run each solver `perf.samples` times and take the median of its timings
+fn measure(
+    solvers: &[Solver],
+    input: Option<&str>,
+    perf: &PerfArgs,
+) -> Result<Vec<(Solver, Timing)>> {
+    ensure!(perf.samples > 0, "At least one sample is needed");
+    solvers
+        .iter()
+        .map(|solver| {
+            let records: Vec<Record> = (0..perf.samples)
+                .map(|_| run_solver(solver, input))
+                .collect();
+            if let Some(record) = records.iter().find(|record| !record.success) {
+                bail!(
+                    "{}: FAILED {}",
+                    record.name(),
+                    record.error.as_deref().unwrap_or_default()
+                );
+            }
+            let median = |time: fn(&Record) -> Option<Duration>| {
+                let mut times: Vec<Duration> = records.iter().filter_map(time).collect();
+                times.sort_unstable();
+                times[times.len() / 2].as_nanos() as u64
+            };
+            let timing = Timing {
+                year: solver.year,
+                day: solver.day,
+                part: solver.part,
+                variant: solver.variant.map(String::from),
+                input_sha256: records[0].input_sha256.clone().unwrap_or_default(),
+                parse_time_ns: median(|record| record.parse_time),
+                solve_time_ns: median(|record| record.solve_time),
+            };
+            Ok((*solver, timing))
+        })
+        .collect()
+}
+
+fn read_baseline(path: &Path) -> Result<Vec<Timing>> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("Failed to read the baseline from {}", path.display()))?;
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("Invalid baseline in {}", path.display()))
+}
+
+fn perf_baseline(solvers: &[Solver], input: Option<&str>, perf: &PerfArgs) -> Result<()> {
+    let mut timings: Vec<Timing> = measure(solvers, input, perf)?
+        .into_iter()
+        .map(|(_, timing)| timing)
+        .collect();
+    // Keep the baseline of the solvers that did not run
+    if perf.baseline.exists() {
+        for timing in read_baseline(&perf.baseline)? {
+            if !timings
+                .iter()
+                .any(|measured| measured.key() == timing.key())
+            {
+                timings.push(timing);
+            }
+        }
+    }
+    timings.sort_by(|a, b| a.key().cmp(&b.key()));
+
+    if let Some(dir) = perf.baseline.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let file = std::fs::File::create(&perf.baseline).with_context(|| {
+        format!(
+            "Failed to write the baseline to {}",
+            perf.baseline.display()
+        )
+    })?;
+    serde_json::to_writer_pretty(std::io::BufWriter::new(file), &timings)?;
+    println!(
+        "Saved the baseline of {} solvers to {}",
+        solvers.len(),
+        perf.baseline.display()
+    );
+    Ok(())
+}
+
+fn perf_check(
+    solvers: &[Solver],
+    input: Option<&str>,
+    perf: &PerfArgs,
+    max_slowdown: f64,
+) -> Result<()> {
+    let baseline = read_baseline(&perf.baseline)?;
+    let mut regressions = Vec::new();
+    for (solver, timing) in measure(solvers, input, perf)? {
+        // The baseline covers all years
+        let name = format!("{} {}", solver.year, solver.name());
+        let expected = match baseline
+            .iter()
+            .find(|expected| expected.key() == timing.key())
+        {
+            Some(expected) if expected.input_sha256 == timing.input_sha256 => expected,
+            Some(_) => {
+                eprintln!("{}: skipped, as the input differs from the baseline", name);
+                continue;
+            }
+            None => {
+                eprintln!("{}: skipped, as it has no baseline", name);
+                continue;
+            }
+        };
+        for (stage, expected, actual) in [
+            ("generator", expected.parse_time_ns, timing.parse_time_ns),
+            ("runner", expected.solve_time_ns, timing.solve_time_ns),
+        ] {
+            let (expected, actual) = (Duration::from_nanos(expected), Duration::from_nanos(actual));
+            let slowdown = actual.as_secs_f64() / expected.as_secs_f64();
+            if slowdown > max_slowdown && actual > expected + PERF_NOISE {
+                regressions.push((name.clone(), stage, expected, actual, slowdown));
+            }
+        }
+    }
+
+    if regressions.is_empty() {
+        println!("No regressions in {} solvers", solvers.len());
+        return Ok(());
+    }
+    let width = regressions.iter().map(|(name, ..)| name.len()).max();
+    println!(
+        "{:<width$} {:<9} {:>12} {:>12} {:>9}",
+        "Solver",
+        "Stage",
+        "Baseline",
+        "Current",
+        "Slowdown",
+        width = width.unwrap_or_default()
+    );
+    for (name, stage, expected, actual, slowdown) in &regressions {
+        println!(
+            "{:<width$} {:<9} {:>12} {:>12} {:>8.2}x",
+            name,
+            stage,
+            format!("{:.2?}", expected),
+            format!("{:.2?}", actual),
+            slowdown,
+            width = width.unwrap_or_default()
+        );
+    }
+    bail!(
+        "{} timings are more than {} times slower than their baseline",
+        regressions.len(),
+        max_slowdown
+    )
+}
@@ -1,6 +1,8 @@
 #![allow(dead_code, unused_imports)]
 
 pub type Result<T, E = anyhow::Error> = std::result::Result<T, E>;
+pub use crate::render::{Image, Render, Rgb};
+pub use crate::solution::{Part, Solution};
 pub use anyhow::anyhow;
 pub use itertools::Itertools as _;
 pub use rayon::prelude::*;
@@ -10,3 +12,423 @@ pub use std::collections::{
     BTreeMap, BTreeMap as Map, BTreeSet, BTreeSet as Set, HashMap, HashSet,
 };
 pub use std::str::FromStr;
+
+/// Error for malformed puzzle input, pointing at the offending position in the input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number, counted in characters
+    pub column: usize,
+    /// What the parser expected to find at this position
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(day: u32, line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Create an error pointing at the start of `part`
+    ///
+    /// `part` must be a subslice of `input`, like the values returned by `lines`, `split` or `split_once`.
+    /// Use an empty slice at the end of a line, e.g., `&line[line.len()..]`, to point behind the last character.
+    pub fn at(day: u32, input: &str, part: &str, expected: impl Into<String>) -> Self {
+        let mut offset = (part.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Self::new(
+            day,
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            expected,
+        )
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid input for day {} at line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A rectangular 2D grid stored in row-major order
+///
+/// Positions are `(x, y)` pairs, where `x` is the column and `y` is the row, with `(0, 0)` in the top left corner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid where every cell contains `value`
+    ///
+    /// Panics if the number of cells overflows, see [`try_new`](Self::try_new) for grids of untrusted sizes.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let len = width
+            .checked_mul(height)
+            .unwrap_or_else(|| panic!("A grid of {}x{} cells is too large", width, height));
+        Self {
+            width,
+            height,
+            cells: vec![value; len],
+        }
+    }
+
+    /// Create a grid like [`new`](Self::new), or `None` if it does not fit into memory
+    pub fn try_new(width: usize, height: usize, value: T) -> Option<Self>
+    where
+        T: Clone,
+    {
+        let len = width.checked_mul(height)?;
+        let mut cells = Vec::new();
+        cells.try_reserve_exact(len).ok()?;
+        cells.resize(len, value);
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Create a grid from a list of rows, which all need to have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            anyhow::ensure!(
+                row.len() == width,
+                "Row {} has length {}, but expected length {}",
+                y,
+                row.len(),
+                width
+            );
+            cells.extend(row);
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Total number of cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn index_of(&self, (x, y): (usize, usize)) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Get the cell at `pos` or `None` if it is outside of the grid
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.index_of(pos).map(move |idx| &mut self.cells[idx])
+    }
+
+    /// Iterate over all positions in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterate over all cells in row-major order
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics for a size of 0, so use 1 for empty grids, which contain no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is out of bounds", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The up to four horizontally and vertically adjacent positions of `pos`
+    ///
+    /// The iterator does not borrow the grid, so the grid can be modified while iterating.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors_with_offsets(pos, &[(0, -1), (1, 0), (0, 1), (-1, 0)])
+    }
+
+    /// The up to eight adjacent positions of `pos`, including diagonals
+    ///
+    /// The iterator does not borrow the grid, so the grid can be modified while iterating.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.neighbors_with_offsets(
+            pos,
+            &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    fn neighbors_with_offsets(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        let height = self.height;
+        offsets.iter().filter_map(move |&(xdiff, ydiff)| {
+            let x2 = x as isize + xdiff;
+            let y2 = y as isize + ydiff;
+            if x2 < 0 || x2 >= width as isize || y2 < 0 || y2 >= height as isize {
+                None
+            } else {
+                Some((x2 as usize, y2 as usize))
+            }
+        })
+    }
+
+    /// Create a new grid of the same size by applying `f` to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: From<u8>> Grid<T> {
+    /// Parse a grid where each cell is a single decimal digit
+    ///
+    /// `day` is only used for error reporting.
+    pub fn from_digits(input: &str, day: u32) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines() {
+            for (idx, c) in line.char_indices() {
+                let digit = c
+                    .to_digit(10)
+                    .ok_or_else(|| ParseError::at(day, input, &line[idx..], "a digit"))?;
+                cells.push(T::from(digit as u8));
+            }
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    let part = &line[line.len().min(width)..];
+                    return Err(ParseError::at(
+                        day,
+                        input,
+                        part,
+                        format!("a row of {} digits", width),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, pos: (usize, usize)) -> &T {
+        match self.index_of(pos) {
+            Some(idx) => &self.cells[idx],
+            None => panic!(
+                "Position {:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Grid<T> {
+    #[track_caller]
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        match self.index_of(pos) {
+            Some(idx) => &mut self.cells[idx],
+            None => panic!(
+                "Position {:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Print every cell without separators and one row per line
+impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+static TEST_GRID: &str = r"123
+456";
+
+#[test]
+fn test_grid_from_digits() -> Result<()> {
+    let grid: Grid<u8> = Grid::from_digits(TEST_GRID, 0)?;
+    assert_eq!(3, grid.width());
+    assert_eq!(2, grid.height());
+    assert_eq!(Some(&1), grid.get((0, 0)));
+    assert_eq!(Some(&6), grid.get((2, 1)));
+    assert_eq!(None, grid.get((3, 0)));
+    assert_eq!(None, grid.get((0, 2)));
+    assert_eq!(5, grid[(1, 1)]);
+    Ok(())
+}
+
+#[test]
+fn test_grid_try_new() {
+    assert_eq!(Some(Grid::new(3, 2, 0_u8)), Grid::try_new(3, 2, 0_u8));
+    assert_eq!(None, Grid::try_new(usize::MAX, 2, 0_u8));
+    assert_eq!(None, Grid::try_new(usize::MAX / 2, 1, 0_u8));
+}
+
+#[test]
+fn test_grid_invalid_input() {
+    assert_eq!(
+        Err(ParseError::new(1, 2, 2, "a digit")),
+        Grid::<u8>::from_digits("12\n3x", 1)
+    );
+    assert_eq!(
+        Err(ParseError::new(1, 2, 3, "a row of 2 digits")),
+        Grid::<u8>::from_digits("12\n345", 1)
+    );
+}
+
+#[test]
+fn test_parse_error_position() {
+    let input = "abc\ndef,ghi\n";
+    let line = input.lines().nth(1).unwrap();
+    let (_, part) = line.split_once(',').unwrap();
+    assert_eq!(
+        ParseError::new(5, 2, 5, "a number"),
+        ParseError::at(5, input, part, "a number")
+    );
+    assert_eq!(
+        ParseError::new(5, 2, 8, "a number"),
+        ParseError::at(5, input, &line[line.len()..], "a number")
+    );
+    assert_eq!(
+        "Invalid input for day 5 at line 2, column 5: expected a number",
+        ParseError::at(5, input, part, "a number").to_string()
+    );
+}
+
+#[test]
+fn test_grid_rows_and_columns() -> Result<()> {
+    let grid: Grid<u8> = Grid::from_digits(TEST_GRID, 0)?;
+    assert_eq!(&[4, 5, 6], grid.row(1));
+    assert_eq!(
+        vec![&[1, 2, 3][..], &[4, 5, 6]],
+        grid.rows().collect::<Vec<_>>()
+    );
+    assert_eq!(vec![&2, &5], grid.column(1).collect::<Vec<_>>());
+    assert_eq!(
+        vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)],
+        grid.positions().collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_grid_neighbors() -> Result<()> {
+    let grid: Grid<u8> = Grid::from_digits(TEST_GRID, 0)?;
+    assert_eq!(
+        vec![(1, 0), (0, 1)],
+        grid.neighbors4((0, 0)).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(2, 0), (1, 1)],
+        grid.neighbors4((2, 1)).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)],
+        grid.neighbors8((1, 0)).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_grid_display() -> Result<()> {
+    let grid: Grid<u8> = Grid::from_digits(TEST_GRID, 0)?;
+    assert_eq!("123\n456\n", grid.to_string());
+    assert_eq!(
+        "#.#\n.#.\n",
+        grid.map(|&d| if d % 2 == 1 { '#' } else { '.' })
+            .to_string()
+    );
+    Ok(())
+}
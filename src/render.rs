@@ -0,0 +1,102 @@
+//! Visualizations of the puzzle state, as ASCII art like in the puzzle descriptions and as images
+//!
+//! Images are written as binary PGM and PPM files of the Netpbm family, which need no dependencies and which most image viewers open.
+
+use crate::prelude::*;
+use std::io::Write;
+
+/// Red, green and blue component of a pixel
+pub type Rgb = [u8; 3];
+
+/// Image of gray or colored pixels
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Image {
+    /// Brightness of each pixel, from black at 0 to white at 255
+    Gray(Grid<u8>),
+    Color(Grid<Rgb>),
+}
+
+impl Image {
+    /// Gray image with one pixel per character, where `.` and spaces are white and all other characters are black
+    ///
+    /// Lines shorter than the longest one are padded with white.
+    pub fn from_ascii(ascii: &str) -> Self {
+        let width = ascii
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut grid = Grid::new(width, ascii.lines().count(), 255);
+        for (y, line) in ascii.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c != '.' && c != ' ' {
+                    grid[(x, y)] = 0;
+                }
+            }
+        }
+        Image::Gray(grid)
+    }
+
+    /// File extension of the format written by [`write_netpbm`](Self::write_netpbm)
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Gray(_) => "pgm",
+            Image::Color(_) => "ppm",
+        }
+    }
+
+    /// Write the image as binary PGM for gray or PPM for colored images
+    pub fn write_netpbm(&self, out: &mut impl Write) -> std::io::Result<()> {
+        match self {
+            Image::Gray(grid) => {
+                write!(out, "P5\n{} {}\n255\n", grid.width(), grid.height())?;
+                out.write_all(&grid.iter().copied().collect::<Vec<u8>>())
+            }
+            Image::Color(grid) => {
+                write!(out, "P6\n{} {}\n255\n", grid.width(), grid.height())?;
+                out.write_all(&grid.iter().flatten().copied().collect::<Vec<u8>>())
+            }
+        }
+    }
+}
+
+/// Puzzle state which can be drawn, like the diagrams in the puzzle descriptions
+///
+/// Drawing fails if the frame is too large to fit into memory.
+pub trait Render {
+    /// ASCII art with one line per row
+    fn ascii(&self) -> Result<String>;
+
+    /// Image of the state, by default the [ASCII art](Self::ascii) with one pixel per character
+    fn image(&self) -> Result<Image> {
+        Ok(Image::from_ascii(&self.ascii()?))
+    }
+}
+
+/// Gray level of `value` in `0..=max`, from white for 0 to black for `max`
+pub fn shade(value: u32, max: u32) -> u8 {
+    255 - (value.min(max) * 255 / max.max(1)) as u8
+}
+
+#[test]
+fn test_from_ascii() {
+    let image = Image::from_ascii("#.\n.\n");
+    assert_eq!(
+        Image::Gray(Grid::from_rows(vec![vec![0, 255], vec![255, 255]]).unwrap()),
+        image
+    );
+}
+
+#[test]
+fn test_write_netpbm() -> Result<()> {
+    let mut pgm = Vec::new();
+    Image::from_ascii("#.").write_netpbm(&mut pgm)?;
+    assert_eq!(b"P5\n2 1\n255\n\x00\xff", &pgm[..]);
+
+    let mut ppm = Vec::new();
+    let image = Image::Color(Grid::from_rows(vec![vec![[1, 2, 3]]])?);
+    assert_eq!("ppm", image.extension());
+    image.write_netpbm(&mut ppm)?;
+    assert_eq!(b"P6\n1 1\n255\n\x01\x02\x03", &ppm[..]);
+    Ok(())
+}
@@ -0,0 +1,220 @@
+//! Common interface of the days, such that tools can run any of them
+
+use crate::inputs::{self, NormalizedInput};
+use crate::prelude::*;
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// The puzzles of one day, implemented by a unit struct in each day, like `Day01` in the day modules of [`y2021`](crate::y2021)
+///
+/// Both parts solve the same parsed input.
+/// A part can have named variants, which solve it in a different way, e.g., to compare their speed.
+pub trait Solution {
+    /// Year of the calendar, which is also the module of the day, e.g., `y2021`
+    const YEAR: u32;
+    /// Day of the calendar
+    const DAY: u32;
+    /// Puzzle input as parsed for both parts
+    type Input: 'static;
+    type Answer1: Display + 'static;
+    type Answer2: Display + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Alternative solvers of the parts, by name
+    fn variants() -> Vec<(&'static str, Part<Self>)> {
+        Vec::new()
+    }
+
+    /// Puzzle state to visualize, usually the one the answer is read from, if the day can draw it
+    fn render(_input: &Self::Input) -> Option<Box<dyn Render>> {
+        None
+    }
+}
+
+/// Solver of a single part, which returns the answer type of that part
+pub enum Part<S: Solution + ?Sized> {
+    One(fn(&S::Input) -> Result<S::Answer1>),
+    Two(fn(&S::Input) -> Result<S::Answer2>),
+}
+
+// Deriving would require `S: Clone`, which the unit structs of the days do not need
+impl<S: Solution + ?Sized> Clone for Part<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Solution + ?Sized> Copy for Part<S> {}
+
+impl<S: Solution + ?Sized> Part<S> {
+    fn number(&self) -> u32 {
+        match self {
+            Part::One(_) => 1,
+            Part::Two(_) => 2,
+        }
+    }
+
+    fn solve(&self, input: &S::Input) -> Result<Box<dyn Display>> {
+        Ok(match self {
+            Part::One(solve) => Box::new(solve(input)?),
+            Part::Two(solve) => Box::new(solve(input)?),
+        })
+    }
+}
+
+/// Object safe form of [`Solution`], such that all days fit into the `SOLUTIONS` of their year, like [`y2021::SOLUTIONS`](crate::y2021::SOLUTIONS)
+pub trait DynSolution: Sync {
+    fn year(&self) -> u32;
+
+    fn day(&self) -> u32;
+
+    /// Solvers of both parts, each followed by its variants
+    fn solvers(&'static self) -> Vec<Solver>;
+
+    /// Parse the input for the solver of `part` with the name `variant`
+    ///
+    /// Fails with a [`ParseError`] for malformed input, or if the day has no such solver.
+    fn parse(&self, input: &str, part: u32, variant: Option<&str>) -> Result<Box<dyn Parsed>>;
+
+    /// Parse the input and visualize it with [`Solution::render`]
+    fn render(&self, input: &str) -> Result<Option<Box<dyn Render>>, ParseError>;
+}
+
+impl<S: Solution + Sync + 'static> DynSolution for S {
+    fn year(&self) -> u32 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn solvers(&'static self) -> Vec<Solver> {
+        let solver = |part, variant| Solver {
+            year: S::YEAR,
+            day: S::DAY,
+            part,
+            variant,
+            solution: self,
+        };
+        let mut solvers = vec![solver(1, None), solver(2, None)];
+        solvers.extend(
+            S::variants()
+                .into_iter()
+                .map(|(name, part)| solver(part.number(), Some(name))),
+        );
+        // The sort is stable, so each part starts with its default solver
+        solvers.sort_by_key(|solver| solver.part);
+        solvers
+    }
+
+    fn parse(&self, input: &str, part: u32, variant: Option<&str>) -> Result<Box<dyn Parsed>> {
+        let solve = match (part, variant) {
+            (1, None) => Part::One(S::part1),
+            (2, None) => Part::Two(S::part2),
+            (_, Some(variant)) => S::variants()
+                .into_iter()
+                .find(|&(name, solve)| name == variant && solve.number() == part)
+                .map(|(_, solve)| solve)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Day {} of {} has no variant {} of part {}",
+                        S::DAY,
+                        S::YEAR,
+                        variant,
+                        part
+                    )
+                })?,
+            _ => anyhow::bail!("Day {} of {} has no part {}", S::DAY, S::YEAR, part),
+        };
+        Ok(Box::new(ParsedInput::<S> {
+            input: S::parse(input)?,
+            solve,
+        }))
+    }
+
+    fn render(&self, input: &str) -> Result<Option<Box<dyn Render>>, ParseError> {
+        Ok(S::render(&S::parse(input)?))
+    }
+}
+
+/// Parsed input of a [`Solver`], which can be solved repeatedly, e.g., in benchmarks
+pub trait Parsed {
+    fn solve(&self) -> Result<Box<dyn Display>>;
+}
+
+struct ParsedInput<S: Solution> {
+    input: S::Input,
+    solve: Part<S>,
+}
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn solve(&self) -> Result<Box<dyn Display>> {
+        self.solve.solve(&self.input)
+    }
+}
+
+/// A part of a day or one of its variants, as listed by [`solvers`]
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub variant: Option<&'static str>,
+    solution: &'static dyn DynSolution,
+}
+
+impl Solver {
+    /// Official puzzle input of the day, `input/<year>/day<day>.txt` in the crate
+    pub fn input_path(&self) -> PathBuf {
+        inputs::day_path(&inputs::input_dir(), self.year, self.day)
+    }
+
+    /// Parse the puzzle input after [normalizing](NormalizedInput) its line endings and whitespace
+    ///
+    /// A [`ParseError`] points at the position in `input`, not in the normalized input.
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Parsed>> {
+        let input = NormalizedInput::new(input);
+        self.solution
+            .parse(input.as_str(), self.part, self.variant)
+            .map_err(|err| match err.downcast::<ParseError>() {
+                Ok(err) => input.original_error(err).into(),
+                Err(err) => err,
+            })
+    }
+
+    /// Parse the puzzle input and solve it
+    pub fn solve(&self, input: &str) -> Result<Box<dyn Display>> {
+        self.parse(input)?.solve()
+    }
+
+    /// Visualize the puzzle state of the day, which is the same for all solvers of the day
+    pub fn render(&self, input: &str) -> Result<Option<Box<dyn Render>>, ParseError> {
+        let input = NormalizedInput::new(input);
+        self.solution
+            .render(input.as_str())
+            .map_err(|err| input.original_error(err))
+    }
+
+    /// Name like `Day 7 - Part 1 - naive`
+    pub fn name(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("Day {} - Part {} - {}", self.day, self.part, variant),
+            None => format!("Day {} - Part {}", self.day, self.part),
+        }
+    }
+}
+
+/// Solvers of all days in [`YEARS`](crate::YEARS), in calendar order
+pub fn solvers() -> Vec<Solver> {
+    crate::YEARS
+        .iter()
+        .flat_map(|solutions| solutions.iter())
+        .flat_map(|solution| solution.solvers())
+        .collect()
+}
@@ -1,48 +1,58 @@
-/// TODO Copy task description here
+//! TODO Copy task description here
+
 use crate::prelude::*;
 
-#[aoc_runner_derive::aoc_generator(day1)]
-fn input_generator(input: &str) -> Result<Vec<u32>> {
+/// TODO Describe the parsed input
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
     todo!()
 }
 
-#[aoc_runner_derive::aoc(day1, part1)]
-fn part1(input: &[u32]) -> Result<u32> {
+/// TODO Describe the answer
+pub fn part1(input: &[u32]) -> Result<u32> {
     todo!()
 }
 
-// #[aoc_runner_derive::aoc(day1, part2)]
-// fn part2(input: &[u32]) -> Result<u32> {
-//     todo!()
-// }
+/// TODO Describe the answer
+pub fn part2(input: &[u32]) -> Result<u32> {
+    todo!()
+}
+
+/// Day 1: TODO Copy the title here
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 1;
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        part2(input)
+    }
+}
 
 #[cfg(test)]
 static TEST_INPUT_1: &str = r"";
 
 #[test]
 fn test_part1() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
+    let values = parse(TEST_INPUT_1)?;
     assert_eq!(todo!(), part1(&values)?);
     Ok(())
 }
 
-// #[test]
-// fn test_part1_solution() -> Result<()> {
-//     let values = input_generator(include_str!("../input/2021/day1.txt").trim())?;
-//     assert_eq!(todo!(), part1(&values)?);
-//     Ok(())
-// }
-
 // #[test]
 // fn test_part2() -> Result<()> {
-//     let values = input_generator(TEST_INPUT_1)?;
-//     assert_eq!(todo!(), part2(&values)?);
-//     Ok(())
-// }
-
-// #[test]
-// fn test_part2_solution() -> Result<()> {
-//     let values = input_generator(include_str!("../input/2021/day1.txt").trim())?;
+//     let values = parse(TEST_INPUT_1)?;
 //     assert_eq!(todo!(), part2(&values)?);
 //     Ok(())
 // }
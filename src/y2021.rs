@@ -0,0 +1,105 @@
+//! Solvers for each day of the 2021 calendar
+//!
+//! Every day has a `parse` function, which turns the puzzle input into the types of that day, and `part1` and `part2` solving the puzzle.
+#![cfg_attr(
+    feature = "y2021-day07",
+    doc = "Some days have additional variants of a part, like [`day07::part1_naive`], and day 10 solves both parts directly on the input."
+)]
+#![cfg_attr(
+    not(feature = "y2021-day07"),
+    doc = "Some days have additional variants of a part, like `day07::part1_naive`, and day 10 solves both parts directly on the input."
+)]
+//! Each day implements [`Solution`](crate::Solution) and is listed in [`SOLUTIONS`].
+//! Only the days enabled through their cargo feature, e.g., `y2021-day07`, are available.
+//! Synthetic inputs for some of the days come from [`generate`].
+//!
+// The example needs its day, which per-day builds may leave out
+#![cfg_attr(
+    feature = "y2021-day01",
+    doc = r#"```
+use advent_of_code_2021::y2021::day01;
+
+let depths = day01::parse("199\n200\n208\n210\n200\n207\n240\n269\n260\n263")?;
+assert_eq!(7, day01::part1(&depths));
+# Ok::<(), advent_of_code_2021::ParseError>(())
+```"#
+)]
+
+pub mod generate;
+
+#[cfg(feature = "y2021-day01")]
+pub mod day01;
+#[cfg(feature = "y2021-day02")]
+pub mod day02;
+#[cfg(feature = "y2021-day03")]
+pub mod day03;
+#[cfg(feature = "y2021-day04")]
+pub mod day04;
+#[cfg(feature = "y2021-day05")]
+pub mod day05;
+#[cfg(feature = "y2021-day06")]
+pub mod day06;
+#[cfg(feature = "y2021-day07")]
+pub mod day07;
+#[cfg(feature = "y2021-day08")]
+pub mod day08;
+#[cfg(feature = "y2021-day09")]
+pub mod day09;
+#[cfg(feature = "y2021-day10")]
+pub mod day10;
+#[cfg(feature = "y2021-day11")]
+pub mod day11;
+#[cfg(feature = "y2021-day12")]
+pub mod day12;
+#[cfg(feature = "y2021-day13")]
+pub mod day13;
+#[cfg(feature = "y2021-day14")]
+pub mod day14;
+#[cfg(feature = "y2021-day15")]
+pub mod day15;
+#[cfg(feature = "y2021-day16")]
+pub mod day16;
+#[cfg(feature = "y2021-day17")]
+pub mod day17;
+#[cfg(feature = "y2021-day18")]
+pub mod day18;
+
+/// All days of 2021 enabled through their cargo feature, in calendar order
+pub static SOLUTIONS: &[&dyn crate::DynSolution] = &[
+    #[cfg(feature = "y2021-day01")]
+    &day01::Day01,
+    #[cfg(feature = "y2021-day02")]
+    &day02::Day02,
+    #[cfg(feature = "y2021-day03")]
+    &day03::Day03,
+    #[cfg(feature = "y2021-day04")]
+    &day04::Day04,
+    #[cfg(feature = "y2021-day05")]
+    &day05::Day05,
+    #[cfg(feature = "y2021-day06")]
+    &day06::Day06,
+    #[cfg(feature = "y2021-day07")]
+    &day07::Day07,
+    #[cfg(feature = "y2021-day08")]
+    &day08::Day08,
+    #[cfg(feature = "y2021-day09")]
+    &day09::Day09,
+    #[cfg(feature = "y2021-day10")]
+    &day10::Day10,
+    #[cfg(feature = "y2021-day11")]
+    &day11::Day11,
+    #[cfg(feature = "y2021-day12")]
+    &day12::Day12,
+    #[cfg(feature = "y2021-day13")]
+    &day13::Day13,
+    #[cfg(feature = "y2021-day14")]
+    &day14::Day14,
+    #[cfg(feature = "y2021-day15")]
+    &day15::Day15,
+    #[cfg(feature = "y2021-day16")]
+    &day16::Day16,
+    #[cfg(feature = "y2021-day17")]
+    &day17::Day17,
+    #[cfg(feature = "y2021-day18")]
+    &day18::Day18,
+];
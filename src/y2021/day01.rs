@@ -92,13 +92,19 @@
 
 use crate::prelude::*;
 
-#[aoc_runner_derive::aoc_generator(day1)]
-fn input_generator(input: &str) -> Result<Vec<u32>> {
-    input.lines().map(|line| Ok(line.parse()?)).collect()
+/// Parse the depth measurements, one per line
+pub fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|_| ParseError::at(1, input, line, "a depth measurement"))
+        })
+        .collect()
 }
 
-#[aoc_runner_derive::aoc(day1, part1)]
-fn part1(input: &[u32]) -> u32 {
+/// Count the measurements which are larger than the previous one
+pub fn part1(input: &[u32]) -> u32 {
     let mut curr = input[0];
     input[1..]
         .iter()
@@ -110,8 +116,8 @@ fn part1(input: &[u32]) -> u32 {
         .sum()
 }
 
-#[aoc_runner_derive::aoc(day1, part2)]
-fn part2(input: &[u32]) -> u32 {
+/// Count the sums of three-measurement sliding windows which are larger than the previous sum
+pub fn part2(input: &[u32]) -> u32 {
     let mut curr_sum = 0;
     input
         .windows(3)
@@ -125,42 +131,41 @@ fn part2(input: &[u32]) -> u32 {
         - 1
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"199
-200
-208
-210
-200
-207
-240
-269
-260
-263";
+/// Day 1: Sonar Sweep
+pub struct Day01;
 
-#[test]
-fn test_part1() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
-    assert_eq!(7, part1(&values));
-    Ok(())
+impl Solution for Day01 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 1;
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(part2(input))
+    }
 }
 
+include!(concat!(env!("OUT_DIR"), "/examples/y2021/day01.rs"));
+
 #[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day1.txt").trim())?;
-    assert_eq!(1228, part1(&values));
+fn test_part1() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(7, part1(&values));
     Ok(())
 }
 
 #[test]
 fn test_part2() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
+    let values = parse(EXAMPLE_1)?;
     assert_eq!(5, part2(&values));
     Ok(())
 }
-
-#[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day1.txt").trim())?;
-    assert_eq!(1257, part2(&values));
-    Ok(())
-}
@@ -65,28 +65,42 @@
 
 use crate::prelude::*;
 
+/// Direction in which a [`Command`] moves the submarine
 #[derive(Deserialize)]
 #[serde(rename_all = "lowercase")]
-enum Direction {
+pub enum Direction {
     Up,
     Down,
     Forward,
 }
 
+/// A single step of the planned course, like `forward 5`
 #[derive(Deserialize, Recap)]
 #[recap(regex = r#"(?P<direction>\S+)\s(?P<distance>\d+)"#)]
-struct Command {
-    direction: Direction,
-    distance: usize,
+pub struct Command {
+    pub direction: Direction,
+    pub distance: usize,
 }
 
-#[aoc_runner_derive::aoc_generator(day2)]
-fn input_generator(input: &str) -> Result<Vec<Command>> {
-    input.lines().map(|line| Ok(line.parse()?)).collect()
+/// Parse the planned course, one command per line
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse().map_err(|_| {
+                ParseError::at(
+                    2,
+                    input,
+                    line,
+                    "a command like `forward 5`, `down 5` or `up 5`",
+                )
+            })
+        })
+        .collect()
 }
 
-#[aoc_runner_derive::aoc(day2, part1)]
-fn part1(input: &[Command]) -> u32 {
+/// Multiply the final horizontal position and depth, when `up` and `down` change the depth
+pub fn part1(input: &[Command]) -> u32 {
     let mut horizontal = 0;
     let mut depth = 0;
 
@@ -100,8 +114,8 @@ fn part1(input: &[Command]) -> u32 {
     (horizontal * depth) as _
 }
 
-#[aoc_runner_derive::aoc(day2, part2)]
-fn part2(input: &[Command]) -> u32 {
+/// Multiply the final horizontal position and depth, when `up` and `down` change the aim
+pub fn part2(input: &[Command]) -> u32 {
     let mut horizontal = 0;
     let mut depth = 0;
     let mut aim = 0;
@@ -119,38 +133,41 @@ fn part2(input: &[Command]) -> u32 {
     (horizontal * depth) as _
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2";
+/// Day 2: Dive!
+pub struct Day02;
 
-#[test]
-fn test_part1() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
-    assert_eq!(150, part1(&values));
-    Ok(())
+impl Solution for Day02 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 2;
+    type Input = Vec<Command>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(part2(input))
+    }
 }
 
+include!(concat!(env!("OUT_DIR"), "/examples/y2021/day02.rs"));
+
 #[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day2.txt").trim())?;
-    assert_eq!(1507611, part1(&values));
+fn test_part1() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(150, part1(&values));
     Ok(())
 }
 
 #[test]
 fn test_part2() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
+    let values = parse(EXAMPLE_1)?;
     assert_eq!(900, part2(&values));
     Ok(())
 }
-
-#[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day2.txt").trim())?;
-    assert_eq!(1880593125, part2(&values));
-    Ok(())
-}
@@ -74,16 +74,24 @@
 
 use crate::prelude::*;
 
-#[aoc_runner_derive::aoc_generator(day3)]
-fn input_generator(input: &str) -> Vec<Vec<bool>> {
+/// Parse the diagnostic report into the bits of each number, most significant first
+pub fn parse(input: &str) -> Result<Vec<Vec<bool>>, ParseError> {
     input
         .lines()
-        .map(|line| line.chars().map(|c| c == '1').collect::<Vec<bool>>())
+        .map(|line| {
+            line.char_indices()
+                .map(|(idx, c)| match c {
+                    '0' => Ok(false),
+                    '1' => Ok(true),
+                    _ => Err(ParseError::at(3, input, &line[idx..], "a binary digit")),
+                })
+                .collect()
+        })
         .collect()
 }
 
-#[aoc_runner_derive::aoc(day3, part1)]
-fn part1(input: &[Vec<bool>]) -> u32 {
+/// Multiply the gamma and epsilon rates, made of the most and least common bits
+pub fn part1(input: &[Vec<bool>]) -> u32 {
     // Initialize all counts with 0
     // true increases the count, false decreases it
     let mut value_counter = vec![0; input[0].len()];
@@ -98,8 +106,8 @@ fn part1(input: &[Vec<bool>]) -> u32 {
     gamma_rate * epsilon_rate
 }
 
-#[aoc_runner_derive::aoc(day3, part2)]
-fn part2(input: &[Vec<bool>]) -> u32 {
+/// Multiply the oxygen generator and CO2 scrubber ratings
+pub fn part2(input: &[Vec<bool>]) -> u32 {
     let mut input_oxygen = input.to_vec();
     let mut input_co2 = input.to_vec();
 
@@ -130,8 +138,13 @@ fn part2(input: &[Vec<bool>]) -> u32 {
         pos += 1;
     }
 
-    let oxygen_generator_rating = dbg!(bits_to_number(input_oxygen[0].clone()));
-    let co2_scrubber_rating = dbg!(bits_to_number(input_co2[0].clone()));
+    let oxygen_generator_rating = bits_to_number(input_oxygen[0].clone());
+    let co2_scrubber_rating = bits_to_number(input_co2[0].clone());
+    log::debug!(
+        "Oxygen generator rating {}, CO2 scrubber rating {}",
+        oxygen_generator_rating,
+        co2_scrubber_rating
+    );
     oxygen_generator_rating * co2_scrubber_rating
 }
 
@@ -147,45 +160,41 @@ fn bits_to_number(i: Vec<bool>) -> u32 {
     res
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010
-";
+/// Day 3: Binary Diagnostic
+pub struct Day03;
 
-#[test]
-fn test_part1() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1);
-    assert_eq!(198, part1(&values));
-    Ok(())
+impl Solution for Day03 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 3;
+    type Input = Vec<Vec<bool>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(part2(input))
+    }
 }
 
+include!(concat!(env!("OUT_DIR"), "/examples/y2021/day03.rs"));
+
 #[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day3.txt").trim());
-    assert_eq!(4006064, part1(&values));
+fn test_part1() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(198, part1(&values));
     Ok(())
 }
 
 #[test]
 fn test_part2() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1);
+    let values = parse(EXAMPLE_1)?;
     assert_eq!(230, part2(&values));
     Ok(())
 }
-
-#[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day3.txt").trim());
-    assert_eq!(5941884, part2(&values));
-    Ok(())
-}
@@ -0,0 +1,353 @@
+//! # Day 4: Giant Squid
+//!
+//! ## --- Part One ---
+//!
+//! You're already almost 1.5km (almost a mile) below the surface of the ocean, already so deep that you can't see any sunlight. What you _can_ see, however, is a giant squid that has attached itself to the outside of your submarine.
+//!
+//! Maybe it wants to play [bingo](https://en.wikipedia.org/wiki/Bingo_(American_version))?
+//!
+//! Bingo is played on a set of boards each consisting of a 5x5 grid of numbers. Numbers are chosen at random, and the chosen number is _marked_ on all boards on which it appears. (Numbers may not appear on all boards.) If all numbers in any row or any column of a board are marked, that board _wins_. (Diagonals don't count.)
+//!
+//! The submarine has a _bingo subsystem_ to help passengers (currently, you and the giant squid) pass the time. It automatically generates a random order in which to draw numbers and a random set of boards (your puzzle input). For example:
+//!
+//! ```text
+//! 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+//!
+//! 22 13 17 11  0
+//!  8  2 23  4 24
+//! 21  9 14 16  7
+//!  6 10  3 18  5
+//!  1 12 20 15 19
+//!
+//!  3 15  0  2 22
+//!  9 18 13 17  5
+//! 19  8  7 25 23
+//! 20 11 10 24  4
+//! 14 21 16 12  6
+//!
+//! 14 21 17 24  4
+//! 10 16 15  9 19
+//! 18  8 23 26 20
+//! 22 11 13  6  5
+//!  2  0 12  3  7
+//! ```
+//!
+//! After the first five numbers are drawn (`7`, `4`, `9`, `5`, and `11`), there are no winners, but the boards are marked as follows (shown here adjacent to each other to save space):
+//!
+//! ```text
+//! 22 13 17 11  0         3 15  0  2 22        14 21 17 24  4
+//!  8  2 23  4 24         9 18 13 17  5        10 16 15  9 19
+//! 21  9 14 16  7        19  8  7 25 23        18  8 23 26 20
+//!  6 10  3 18  5        20 11 10 24  4        22 11 13  6  5
+//!  1 12 20 15 19        14 21 16 12  6         2  0 12  3  7
+//! ```
+//!
+//! After the next six numbers are drawn (`17`, `23`, `2`, `0`, `14`, and `21`), there are still no winners:
+//!
+//! ```text
+//! 22 13 17 11  0         3 15  0  2 22        14 21 17 24  4
+//!  8  2 23  4 24         9 18 13 17  5        10 16 15  9 19
+//! 21  9 14 16  7        19  8  7 25 23        18  8 23 26 20
+//!  6 10  3 18  5        20 11 10 24  4        22 11 13  6  5
+//!  1 12 20 15 19        14 21 16 12  6         2  0 12  3  7
+//! ```
+//!
+//! Finally, `24` is drawn:
+//!
+//! ```text
+//! 22 13 17 11  0         3 15  0  2 22        14 21 17 24  4
+//!  8  2 23  4 24         9 18 13 17  5        10 16 15  9 19
+//! 21  9 14 16  7        19  8  7 25 23        18  8 23 26 20
+//!  6 10  3 18  5        20 11 10 24  4        22 11 13  6  5
+//!  1 12 20 15 19        14 21 16 12  6         2  0 12  3  7
+//! ```
+//!
+//! At this point, the third board _wins_ because it has at least one complete row or column of marked numbers (in this case, the entire top row is marked: _`14 21 17 24 4`_).
+//!
+//! The _score_ of the winning board can now be calculated. Start by finding the _sum of all unmarked numbers_ on that board; in this case, the sum is `188`. Then, multiply that sum by _the number that was just called_ when the board won, `24`, to get the final score, `188 * 24 = 4512`.
+//!
+//! To guarantee victory against the giant squid, figure out which board will win first. _What will your final score be if you choose that board?_
+//!
+//! ## --- Part Two ---
+//!
+//! On the other hand, it might be wise to try a different strategy: <span title="That's 'cuz a submarine don't pull things' antennas out of their sockets when they lose. Giant squid are known to do that.">let the giant squid win</span>.
+//!
+//! You aren't sure how many bingo boards a giant squid could play at once, so rather than waste time counting its arms, the safe thing to do is to _figure out which board will win last_ and choose that one. That way, no matter which boards it picks, it will win for sure.
+//!
+//! In the above example, the second board is the last to win, which happens after `13` is eventually called and its middle column is completely marked. If you were to keep playing until this point, the second board would have a sum of unmarked numbers equal to `148` for a final score of `148 * 13 = 1924`.
+//!
+//! Figure out which board will win last. _Once it wins, what would its final score be?_
+
+#[cfg(test)]
+use crate::inputs::{windows_style, NormalizedInput};
+use crate::prelude::*;
+
+/// The drawn numbers and the bingo boards
+#[derive(Clone)]
+pub struct Input {
+    /// Numbers in the order they are drawn
+    pub numbers: Vec<i32>,
+    pub boards: Vec<Board>,
+}
+
+/// A bingo board, which keeps track of its marked numbers
+#[derive(Clone, Debug)]
+pub struct Board {
+    fields: Vec<Vec<i32>>,
+}
+
+impl Board {
+    /// Mark the fields with `value`
+    pub fn mark(&mut self, value: i32) {
+        for row in &mut self.fields {
+            for field in row {
+                if *field == value {
+                    *field = -1;
+                }
+            }
+        }
+    }
+
+    /// Sum of the numbers which are not marked yet
+    pub fn sum_unmarked(&self) -> i32 {
+        self.fields.iter().flatten().filter(|&&x| x >= 0).sum()
+    }
+
+    /// All fields in a row or column need to be marked
+    pub fn is_solved(&self) -> bool {
+        self.fields.iter().any(|row| row.iter().all(|&x| x == -1))
+            || (0..self.fields[0].len())
+                .any(|col_idx| self.fields.iter().all(|row| row[col_idx] == -1))
+    }
+}
+
+/// Parse the drawn numbers and the boards following them
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let parse_number = |s: &str| {
+        s.trim()
+            .parse()
+            .map_err(|_| ParseError::at(4, input, s, "a number"))
+    };
+
+    let mut blocks = input.split("\n\n");
+    let numbers = blocks
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(parse_number)
+        .collect::<Result<_, _>>()?;
+    let boards = blocks
+        .map(|board| {
+            let fields = board
+                .split('\n')
+                .map(|row| {
+                    // Each number is right-aligned in a column of width 2, separated by a single space
+                    (0..row.len())
+                        .step_by(3)
+                        .map(|i| {
+                            let field = row.get(i..i + 2).ok_or_else(|| {
+                                let part = row.get(i..).unwrap_or(row);
+                                ParseError::at(4, input, part, "a two character wide number")
+                            })?;
+                            parse_number(field)
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Board { fields })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Input { numbers, boards })
+}
+
+/// Score of the first board to win, the sum of its unmarked numbers times the last drawn number
+pub fn part1(input: &Input) -> Result<i32> {
+    let mut input = input.clone();
+    for value in &input.numbers {
+        for board in &mut input.boards {
+            board.mark(*value);
+            if board.is_solved() {
+                return Ok(board.sum_unmarked() * value);
+            }
+        }
+    }
+
+    Err(anyhow!("No board wins"))
+}
+
+/// Score of the last board to win
+pub fn part2(input: &Input) -> Result<i32> {
+    let mut input = input.clone();
+    for value in &input.numbers {
+        for board_idx in (0..input.boards.len()).rev() {
+            input.boards[board_idx].mark(*value);
+            if input.boards[board_idx].is_solved() {
+                if input.boards.len() == 1 {
+                    // Last winning board
+                    return Ok(input.boards[board_idx].sum_unmarked() * value);
+                }
+                input.boards.remove(board_idx);
+            }
+        }
+    }
+    Err(anyhow!("Not all boards win"))
+}
+
+/// The boards at the moment the first one wins
+pub struct Bingo {
+    boards: Vec<Board>,
+    drawn: Set<i32>,
+    winner: Option<usize>,
+}
+
+impl Bingo {
+    /// Draw the numbers until a board wins, or all of them if none does
+    pub fn first_win(input: &Input) -> Self {
+        let mut marked_boards = input.boards.clone();
+        let mut drawn = Set::new();
+        let mut winner = None;
+        for &value in &input.numbers {
+            drawn.insert(value);
+            for board in &mut marked_boards {
+                board.mark(value);
+            }
+            winner = marked_boards.iter().position(Board::is_solved);
+            if winner.is_some() {
+                break;
+            }
+        }
+        Self {
+            boards: input.boards.clone(),
+            drawn,
+            winner,
+        }
+    }
+}
+
+impl Render for Bingo {
+    /// One board after the other, with `*` in front of the drawn numbers
+    fn ascii(&self) -> Result<String> {
+        Ok(self
+            .boards
+            .iter()
+            .map(|board| {
+                board
+                    .fields
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|&field| {
+                                if self.drawn.contains(&field) {
+                                    format!("{:>3}", format!("*{}", field))
+                                } else {
+                                    format!("{:>3}", field)
+                                }
+                            })
+                            .join("")
+                            + "\n"
+                    })
+                    .join("")
+            })
+            .join("\n"))
+    }
+
+    /// Ten boards per row, with the drawn numbers in gray, or green on the winning board
+    fn image(&self) -> Result<Image> {
+        const BOARDS_PER_ROW: usize = 10;
+        let size = self.boards.first().map_or(0, |board| board.fields.len());
+        let columns = self.boards.len().min(BOARDS_PER_ROW);
+        let rows = (self.boards.len() + BOARDS_PER_ROW - 1) / BOARDS_PER_ROW;
+        let mut grid = Grid::new(
+            columns * (size + 1) + 1,
+            rows * (size + 1) + 1,
+            [224, 224, 224],
+        );
+        for (idx, board) in self.boards.iter().enumerate() {
+            let left = idx % BOARDS_PER_ROW * (size + 1) + 1;
+            let top = idx / BOARDS_PER_ROW * (size + 1) + 1;
+            for (y, row) in board.fields.iter().enumerate() {
+                for (x, field) in row.iter().enumerate() {
+                    grid[(left + x, top + y)] = match self.drawn.contains(field) {
+                        true if self.winner == Some(idx) => [0, 160, 0],
+                        true => [96, 96, 96],
+                        false => [255, 255, 255],
+                    };
+                }
+            }
+        }
+        Ok(Image::Color(grid))
+    }
+}
+
+/// Day 4: Giant Squid
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 4;
+    type Input = Input;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32> {
+        part2(input)
+    }
+
+    fn render(input: &Self::Input) -> Option<Box<dyn Render>> {
+        Some(Box::new(Bingo::first_win(input)))
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples/y2021/day04.rs"));
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(4512, part1(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(1924, part2(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_windows_style() -> Result<()> {
+    let input = windows_style(EXAMPLE_1);
+    let values = parse(NormalizedInput::new(&input).as_str())?;
+    assert_eq!(4512, part1(&values)?);
+    assert_eq!(1924, part2(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_no_winner() -> Result<()> {
+    // Only the first two numbers of the example are drawn
+    let (_, boards) = EXAMPLE_1.split_once("\n\n").unwrap();
+    let values = parse(&format!("7,4\n\n{}", boards))?;
+    assert!(part1(&values).is_err());
+    assert!(part2(&values).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_bingo() -> Result<()> {
+    let bingo = Bingo::first_win(&parse(EXAMPLE_1)?);
+    assert_eq!(Some(2), bingo.winner);
+    let ascii = bingo.ascii()?;
+    let third_board: Vec<&str> = ascii.lines().skip(12).take(2).collect();
+    assert_eq!(vec!["*14*21*17*24 *4", " 10 16 15 *9 19"], third_board);
+    Ok(())
+}
@@ -0,0 +1,939 @@
+//! # Day 5: Hydrothermal Venture
+//!
+//! ## --- Part One ---
+//!
+//! You come across a field of [hydrothermal vents](https://en.wikipedia.org/wiki/Hydrothermal_vent) on the ocean floor! These vents constantly produce large, opaque clouds, so it would be best to avoid them if possible.
+//!
+//! They tend to form in _lines_; the submarine helpfully produces a list of nearby <span title="Maybe they're Bresenham vents.">lines of vents</span> (your puzzle input) for you to review. For example:
+//!
+//! ```text
+//! 0,9 -> 5,9
+//! 8,0 -> 0,8
+//! 9,4 -> 3,4
+//! 2,2 -> 2,1
+//! 7,0 -> 7,4
+//! 6,4 -> 2,0
+//! 0,9 -> 2,9
+//! 3,4 -> 1,4
+//! 0,0 -> 8,8
+//! 5,5 -> 8,2
+//! ```
+//!
+//! Each line of vents is given as a line segment in the format `x1,y1 -> x2,y2` where `x1`,`y1` are the coordinates of one end the line segment and `x2`,`y2` are the coordinates of the other end. These line segments include the points at both ends. In other words:
+//!
+//! * An entry like `1,1 -> 1,3` covers points `1,1`, `1,2`, and `1,3`.
+//! * An entry like `9,7 -> 7,7` covers points `9,7`, `8,7`, and `7,7`.
+//!
+//! For now, _only consider horizontal and vertical lines_: lines where either `x1 = x2` or `y1 = y2`.
+//!
+//! So, the horizontal and vertical lines from the above list would produce the following diagram:
+//!
+//! ```text
+//! .......1..
+//! ..1....1..
+//! ..1....1..
+//! .......1..
+//! .112111211
+//! ..........
+//! ..........
+//! ..........
+//! ..........
+//! 222111....
+//! ```
+//!
+//! In this diagram, the top left corner is `0,0` and the bottom right corner is `9,9`. Each position is shown as _the number of lines which cover that point_ or `.` if no line covers that point. The top-left pair of `1`s, for example, comes from `2,2 -> 2,1`; the very bottom row is formed by the overlapping lines `0,9 -> 5,9` and `0,9 -> 2,9`.
+//!
+//! To avoid the most dangerous areas, you need to determine _the number of points where at least two lines overlap_. In the above example, this is anywhere in the diagram with a `2` or larger - a total of _`5`_ points.
+//!
+//! Consider only horizontal and vertical lines. _At how many points do at least two lines overlap?_
+//!
+//! ## --- Part Two ---
+//!
+//! Unfortunately, considering only horizontal and vertical lines doesn't give you the full picture; you need to also consider _diagonal lines_.
+//!
+//! Because of the limits of the hydrothermal vent mapping system, the lines in your list will only ever be horizontal, vertical, or a diagonal line at exactly 45 degrees. In other words:
+//!
+//! * An entry like `1,1 -> 3,3` covers points `1,1`, `2,2`, and `3,3`.
+//! * An entry like `9,7 -> 7,9` covers points `9,7`, `8,8`, and `7,9`.
+//!
+//! Considering all lines from the above example would now produce the following diagram:
+//!
+//! ```text
+//! 1.1....11.
+//! .111...2..
+//! ..2.1.111.
+//! ...1.2.2..
+//! .112313211
+//! ...1.2....
+//! ..1...1...
+//! .1.....1..
+//! 1.......1.
+//! 222111....
+//! ```
+//!
+//! You still need to determine _the number of points where at least two lines overlap_. In the above example, this is still anywhere in the diagram with a `2` or larger - now a total of _`12`_ points.
+//!
+//! Consider all of the lines. _At how many points do at least two lines overlap?_
+
+#[cfg(test)]
+use crate::inputs::{windows_style, NormalizedInput};
+use crate::prelude::*;
+
+/// Position on the ocean floor
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: u32,
+    pub y: u32,
+}
+
+/// Parse the lines of hydrothermal vents, each from one end point to the other
+///
+/// Lines may have any slope, as in generalized vent surveys. Use [`parse_strict`] to only allow the lines of the puzzle.
+pub fn parse(input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+    let parse_point = |point: &str| -> Result<Point, ParseError> {
+        let (x, y) = point
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(5, input, &point[point.len()..], "`,`"))?;
+        let parse_coordinate = |value: &str| {
+            value
+                .parse()
+                .map_err(|_| ParseError::at(5, input, value, "a coordinate"))
+        };
+        Ok(Point {
+            x: parse_coordinate(x)?,
+            y: parse_coordinate(y)?,
+        })
+    };
+
+    input
+        .lines()
+        .map(|line| {
+            let (start, end) = line
+                .split_once(" -> ")
+                .ok_or_else(|| ParseError::at(5, input, &line[line.len()..], "` -> `"))?;
+            Ok((parse_point(start)?, parse_point(end)?))
+        })
+        .collect()
+}
+
+/// Parse the lines like [`parse`], but reject lines which are neither horizontal, vertical nor diagonal at 45 degrees
+pub fn parse_strict(input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+    let lines = parse(input)?;
+    for (line, text) in lines.iter().zip(input.lines()) {
+        if !is_straight(line) && !is_diagonal(line) {
+            return Err(ParseError::at(
+                5,
+                input,
+                text,
+                "a horizontal, vertical or 45 degree line",
+            ));
+        }
+    }
+    Ok(lines)
+}
+
+/// Count the points where at least two horizontal or vertical lines overlap
+pub fn part1(input: &[(Point, Point)]) -> usize {
+    let overlaps = VentMap::new(input, false).count_at_least(2);
+    // Only counts of all points can be too large
+    overlaps.unwrap() as usize
+}
+
+/// Count the points where at least two lines overlap, including the diagonal ones of any slope
+pub fn part2(input: &[(Point, Point)]) -> usize {
+    let overlaps = VentMap::new(input, true).count_at_least(2);
+    // Only counts of all points can be too large
+    overlaps.unwrap() as usize
+}
+
+/// Whether the line is horizontal or vertical
+fn is_straight((start, end): &(Point, Point)) -> bool {
+    start.x == end.x || start.y == end.y
+}
+
+/// Whether the line is diagonal at exactly 45 degrees
+fn is_diagonal((start, end): &(Point, Point)) -> bool {
+    start.x.abs_diff(end.x) == start.y.abs_diff(end.y) && start.x != end.x
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Number of steps from the start to the end of a line, where each step moves to the next point with integer coordinates
+///
+/// This is the greatest common divisor of the differences of the coordinates, e.g., 2 for `0,0 -> 4,2` over `2,1`.
+fn line_length((start, end): &(Point, Point)) -> u32 {
+    gcd(start.x.abs_diff(end.x), start.y.abs_diff(end.y))
+}
+
+/// Points with integer coordinates on a line of any slope, from its start to its end
+fn line_points(line: &(Point, Point)) -> impl Iterator<Item = (u32, u32)> {
+    let (start, end) = line;
+    let (x, y) = (start.x as i64, start.y as i64);
+    let length = line_length(line) as i64;
+    // A line from a point to itself has no steps
+    let (step_x, step_y) = match length {
+        0 => (0, 0),
+        _ => ((end.x as i64 - x) / length, (end.y as i64 - y) / length),
+    };
+    (0..=length).map(move |i| ((x + i * step_x) as u32, (y + i * step_y) as u32))
+}
+
+/// Number of lines covering each point
+///
+/// The counts are stored densely over the bounding box of the lines, unless that box is much larger than the number
+/// of covered points, e.g., for a few short lines millions apart. Then only the covered points are stored in a hash map.
+///
+/// Drawing the map shows the diagrams of the puzzle description, over the bounding box of the lines.
+pub struct VentMap {
+    /// Smallest and largest coordinates of the lines, if there are any
+    bounds: Option<(Point, Point)>,
+    counts: Counts,
+}
+
+enum Counts {
+    /// Counts of the bounding box, which starts at `min`
+    Dense {
+        min: Point,
+        grid: Grid<u32>,
+    },
+    Sparse(HashMap<(u32, u32), u32>),
+}
+
+impl VentMap {
+    /// Boxes up to this many cells are always stored densely, as they take just a few megabytes
+    const MIN_DENSE_CELLS: u64 = 1 << 20;
+    /// Larger boxes are only stored densely if they have at most this many cells per covered point
+    const DENSE_CELLS_PER_POINT: u64 = 4;
+
+    /// Map of the horizontal and vertical lines, and of the diagonal ones of any slope if `diagonals` is set
+    pub fn new(lines: &[(Point, Point)], diagonals: bool) -> Self {
+        let lines: Vec<&(Point, Point)> = lines
+            .iter()
+            .filter(|line| diagonals || is_straight(line))
+            .collect();
+        let points = lines.iter().flat_map(|line| line_points(line));
+
+        let bounds = lines.iter().flat_map(|(start, end)| [start, end]).fold(
+            None,
+            |bounds: Option<(Point, Point)>, &point| {
+                let (min, max) = bounds.unwrap_or((point, point));
+                Some((
+                    Point {
+                        x: min.x.min(point.x),
+                        y: min.y.min(point.y),
+                    },
+                    Point {
+                        x: max.x.max(point.x),
+                        y: max.y.max(point.y),
+                    },
+                ))
+            },
+        );
+        let (min, max) = match bounds {
+            Some(bounds) => bounds,
+            None => {
+                return Self {
+                    bounds,
+                    counts: Counts::Sparse(HashMap::new()),
+                }
+            }
+        };
+        let (width, height) = ((max.x - min.x) as u64 + 1, (max.y - min.y) as u64 + 1);
+        let covered: u64 = lines.iter().map(|line| line_length(line) as u64 + 1).sum();
+
+        // Boxes spanning all coordinates have more cells than fit into a u64, and are stored sparsely
+        let dense_cells = Self::DENSE_CELLS_PER_POINT
+            .checked_mul(covered)
+            .map_or(u64::MAX, |cells| cells.max(Self::MIN_DENSE_CELLS));
+        let counts = if width
+            .checked_mul(height)
+            .map_or(false, |cells| cells <= dense_cells)
+        {
+            let mut grid = Grid::new(width as usize, height as usize, 0);
+            for (x, y) in points {
+                grid[((x - min.x) as usize, (y - min.y) as usize)] += 1;
+            }
+            Counts::Dense { min, grid }
+        } else {
+            let mut counts = HashMap::new();
+            for point in points {
+                *counts.entry(point).or_insert(0) += 1;
+            }
+            Counts::Sparse(counts)
+        };
+        Self { bounds, counts }
+    }
+
+    /// Number of lines covering `point`
+    pub fn coverage(&self, point: Point) -> u32 {
+        match &self.counts {
+            Counts::Dense { min, grid } => {
+                if point.x < min.x || point.y < min.y {
+                    return 0;
+                }
+                let pos = ((point.x - min.x) as usize, (point.y - min.y) as usize);
+                grid.get(pos).copied().unwrap_or(0)
+            }
+            Counts::Sparse(counts) => counts.get(&(point.x, point.y)).copied().unwrap_or(0),
+        }
+    }
+
+    /// Number of points with any coordinates which are covered by at least `k` lines, e.g., the overlaps of the puzzle for 2
+    ///
+    /// Like [`count_at_least_in`](Self::count_at_least_in), this is `None` if the count does not fit into a `u64`.
+    /// That is the case for 0, which all 2^64 points satisfy.
+    pub fn count_at_least(&self, k: u32) -> Option<u64> {
+        if k == 0 {
+            return None;
+        }
+        let count = match &self.counts {
+            Counts::Dense { grid, .. } => grid.iter().filter(|&&count| count >= k).count(),
+            Counts::Sparse(counts) => counts.values().filter(|&&count| count >= k).count(),
+        };
+        Some(count as u64)
+    }
+
+    /// Number of covered points for each number of lines covering them
+    pub fn histogram(&self) -> Map<u32, usize> {
+        let mut histogram = Map::new();
+        let counts: Box<dyn Iterator<Item = &u32>> = match &self.counts {
+            Counts::Dense { grid, .. } => Box::new(grid.iter()),
+            Counts::Sparse(counts) => Box::new(counts.values()),
+        };
+        for &count in counts.filter(|&&count| count > 0) {
+            *histogram.entry(count).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Points covered by at least one line in the rectangle from `min` to `max`, with the number of lines covering them
+    ///
+    /// Dense maps yield the points in row-major order, sparse maps in no particular order.
+    pub fn covered_in(
+        &self,
+        min: Point,
+        max: Point,
+    ) -> Box<dyn Iterator<Item = (Point, u32)> + '_> {
+        match &self.counts {
+            Counts::Dense { min: origin, grid } => {
+                // Clip the rectangle to the bounding box, which is empty if they do not intersect
+                let columns = min.x.max(origin.x)..=max.x.min(origin.x + (grid.width() - 1) as u32);
+                let rows = min.y.max(origin.y)..=max.y.min(origin.y + (grid.height() - 1) as u32);
+                let origin = *origin;
+                Box::new(
+                    rows.flat_map(move |y| columns.clone().map(move |x| Point { x, y }))
+                        .map(move |point| {
+                            let pos =
+                                ((point.x - origin.x) as usize, (point.y - origin.y) as usize);
+                            (point, grid[pos])
+                        })
+                        .filter(|&(_, count)| count > 0),
+                )
+            }
+            Counts::Sparse(counts) => Box::new(
+                counts
+                    .iter()
+                    .map(|(&(x, y), &count)| (Point { x, y }, count))
+                    .filter(move |(point, _)| {
+                        (min.x..=max.x).contains(&point.x) && (min.y..=max.y).contains(&point.y)
+                    }),
+            ),
+        }
+    }
+
+    /// Number of points in the rectangle from `min` to `max` which are covered by at least `k` lines
+    ///
+    /// For 0 this is the area of the rectangle, which is `None` if it does not fit into a `u64`.
+    pub fn count_at_least_in(&self, min: Point, max: Point, k: u32) -> Option<u64> {
+        if k == 0 {
+            let side = |from: u32, to: u32| (to as u64 + 1).saturating_sub(from as u64);
+            return side(min.x, max.x).checked_mul(side(min.y, max.y));
+        }
+        let count = self
+            .covered_in(min, max)
+            .filter(|&(_, count)| count >= k)
+            .count();
+        Some(count as u64)
+    }
+
+    /// Diagram of the rectangle from `min` to `max` like in the puzzle description
+    ///
+    /// Each point shows the number of lines covering it, `.` for none and `*` for more than 9.
+    pub fn diagram(&self, min: Point, max: Point) -> String {
+        let mut diagram = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                diagram.push(match self.coverage(Point { x, y }) {
+                    0 => '.',
+                    count @ 1..=9 => char::from_digit(count, 10).unwrap(),
+                    _ => '*',
+                });
+            }
+            diagram.push('\n');
+        }
+        diagram
+    }
+
+    /// Whether only the covered points are stored, because the bounding box of the lines is too large
+    pub fn is_sparse(&self) -> bool {
+        matches!(self.counts, Counts::Sparse(_))
+    }
+}
+
+impl VentMap {
+    /// Number of lines covering each point of the bounding box, which fails if the box does not fit into memory
+    fn frame(&self) -> Result<Grid<u32>> {
+        let (min, max) = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Ok(Grid::new(0, 0, 0)),
+        };
+        let (width, height) = ((max.x - min.x) as usize + 1, (max.y - min.y) as usize + 1);
+        let mut frame = Grid::try_new(width, height, 0).ok_or_else(|| {
+            anyhow!(
+                "The map of {}x{} points is too large to draw",
+                width,
+                height
+            )
+        })?;
+        for (point, count) in self.covered_in(min, max) {
+            frame[((point.x - min.x) as usize, (point.y - min.y) as usize)] = count;
+        }
+        Ok(frame)
+    }
+}
+
+/// The diagrams of the puzzle description, clipped to the bounding box of the lines
+impl Render for VentMap {
+    /// Like [`diagram`](VentMap::diagram) of the bounding box
+    fn ascii(&self) -> Result<String> {
+        let frame = self.frame()?.map(|&count| match count {
+            0 => '.',
+            count @ 1..=9 => char::from_digit(count, 10).unwrap(),
+            _ => '*',
+        });
+        Ok(frame.to_string())
+    }
+
+    /// Darker points are covered by more lines
+    fn image(&self) -> Result<Image> {
+        let max_count = self.histogram().keys().last().copied().unwrap_or(0);
+        let frame = self.frame()?;
+        Ok(Image::Gray(
+            frame.map(|&count| crate::render::shade(count, max_count)),
+        ))
+    }
+}
+
+/// Count the points where at least two horizontal or vertical lines overlap, without visiting the points of the lines
+pub fn part1_analytic(input: &[(Point, Point)]) -> usize {
+    count_overlaps(input, false)
+}
+
+/// Count the points where at least two lines of any slope overlap, without visiting the points of the lines
+pub fn part2_analytic(input: &[(Point, Point)]) -> usize {
+    count_overlaps(input, true)
+}
+
+/// Count the points covered by at least two lines like [`VentMap::count_at_least`], but in time independent of the line lengths
+///
+/// Lines on the same infinite line, their carrier, overlap in ranges found by sweeping over their ends.
+/// Lines on different carriers cross in at most one point, which is found with exact integer math for every pair.
+/// Crossings inside the overlaps of several carriers are counted only once.
+pub fn count_overlaps(lines: &[(Point, Point)], diagonals: bool) -> usize {
+    let segments: Vec<Segment> = lines
+        .iter()
+        .filter(|line| diagonals || is_straight(line))
+        .map(Segment::new)
+        .collect();
+
+    let mut spans: HashMap<Carrier, Vec<(i64, i64)>> = HashMap::new();
+    for segment in &segments {
+        spans.entry(segment.carrier).or_default().push(segment.span);
+    }
+    let overlaps: HashMap<Carrier, Vec<(i64, i64)>> = spans
+        .into_iter()
+        .map(|(carrier, spans)| (carrier, overlapping_spans(&spans)))
+        .collect();
+    let mut count: i64 = overlaps
+        .values()
+        .flatten()
+        .map(|(first, last)| last - first + 1)
+        .sum();
+
+    let mut crossings: HashMap<(i64, i64), HashSet<Carrier>> = HashMap::new();
+    for (idx, first) in segments.iter().enumerate() {
+        for second in &segments[idx + 1..] {
+            if first.carrier == second.carrier {
+                continue;
+            }
+            if let Some(point) = first.crossing(second) {
+                let carriers = crossings.entry(point).or_default();
+                carriers.insert(first.carrier);
+                carriers.insert(second.carrier);
+            }
+        }
+    }
+    // Each crossing was counted once for every carrier with an overlap there, but needs to be counted exactly once
+    for (point, carriers) in crossings {
+        let counted = carriers
+            .iter()
+            .filter(|carrier| {
+                let position = carrier.position(point);
+                let overlaps = &overlaps[carrier];
+                let idx = overlaps.partition_point(|&(_, last)| last < position);
+                overlaps
+                    .get(idx)
+                    .map_or(false, |&(first, _)| first <= position)
+            })
+            .count();
+        count += 1 - counted as i64;
+    }
+    count as usize
+}
+
+/// Infinite line through a segment, shared by all segments which can overlap it in more than one point
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Carrier {
+    /// Segments from a point to itself, which only overlap segments through that point
+    Point(i64, i64),
+    /// Line with the primitive `direction`, pointing right or down, through the points where `b * x - a * y` is `offset`
+    Line { direction: (i64, i64), offset: i128 },
+}
+
+impl Carrier {
+    /// Index of a point of the carrier, which increases by one from each point with integer coordinates to the next
+    fn position(&self, (x, y): (i64, i64)) -> i64 {
+        match *self {
+            Carrier::Point(..) => 0,
+            Carrier::Line {
+                direction: (0, step_y),
+                ..
+            } => y.div_euclid(step_y),
+            Carrier::Line {
+                direction: (step_x, _),
+                ..
+            } => x.div_euclid(step_x),
+        }
+    }
+}
+
+/// Line of vents on its carrier
+struct Segment {
+    carrier: Carrier,
+    start: (i64, i64),
+    /// Step to the next point with integer coordinates towards the end
+    step: (i64, i64),
+    steps: i64,
+    /// First and last position on the carrier
+    span: (i64, i64),
+}
+
+impl Segment {
+    fn new(line: &(Point, Point)) -> Self {
+        let (start, end) = line;
+        let start = (start.x as i64, start.y as i64);
+        let steps = line_length(line) as i64;
+        let (step, carrier) = if steps == 0 {
+            ((0, 0), Carrier::Point(start.0, start.1))
+        } else {
+            let step = (
+                (end.x as i64 - start.0) / steps,
+                (end.y as i64 - start.1) / steps,
+            );
+            let (a, b) = if step.0 < 0 || (step.0 == 0 && step.1 < 0) {
+                (-step.0, -step.1)
+            } else {
+                step
+            };
+            let offset = b as i128 * start.0 as i128 - a as i128 * start.1 as i128;
+            let carrier = Carrier::Line {
+                direction: (a, b),
+                offset,
+            };
+            (step, carrier)
+        };
+        let end = (start.0 + steps * step.0, start.1 + steps * step.1);
+        let (first, last) = (carrier.position(start), carrier.position(end));
+        Self {
+            carrier,
+            start,
+            step,
+            steps,
+            span: (first.min(last), first.max(last)),
+        }
+    }
+
+    /// The point with integer coordinates where this segment meets a segment on another carrier
+    fn crossing(&self, other: &Segment) -> Option<(i64, i64)> {
+        let cross = |(x1, y1): (i64, i64), (x2, y2): (i64, i64)| {
+            x1 as i128 * y2 as i128 - y1 as i128 * x2 as i128
+        };
+        let offset = (other.start.0 - self.start.0, other.start.1 - self.start.1);
+        let denominator = cross(self.step, other.step);
+        // Parallel segments on different carriers never meet, and single points are parallel to everything
+        if denominator == 0 {
+            return match (self.steps, other.steps) {
+                (0, _) => other.contains(self.start).then_some(self.start),
+                (_, 0) => self.contains(other.start).then_some(other.start),
+                _ => None,
+            };
+        }
+        // Solve `self.start + u * self.step == other.start + v * other.step`
+        let (u, v) = (cross(offset, other.step), cross(offset, self.step));
+        if u % denominator != 0 || v % denominator != 0 {
+            return None;
+        }
+        let (u, v) = ((u / denominator) as i64, (v / denominator) as i64);
+        if !(0..=self.steps).contains(&u) || !(0..=other.steps).contains(&v) {
+            return None;
+        }
+        Some((
+            self.start.0 + u * self.step.0,
+            self.start.1 + u * self.step.1,
+        ))
+    }
+
+    fn contains(&self, point: (i64, i64)) -> bool {
+        match self.carrier {
+            Carrier::Point(x, y) => point == (x, y),
+            Carrier::Line { direction, offset } => {
+                let on_carrier = direction.1 as i128 * point.0 as i128
+                    - direction.0 as i128 * point.1 as i128
+                    == offset;
+                let position = self.carrier.position(point);
+                on_carrier && self.span.0 <= position && position <= self.span.1
+            }
+        }
+    }
+}
+
+/// Sorted and disjoint ranges of positions covered by at least two of the `spans` on one carrier
+fn overlapping_spans(spans: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    // Ends sort before starts at the same position, as the span ends before that position
+    let mut events: Vec<(i64, i32)> = spans
+        .iter()
+        .flat_map(|&(first, last)| [(first, 1), (last + 1, -1)])
+        .collect();
+    events.sort_unstable();
+
+    let mut overlaps = Vec::new();
+    let mut coverage = 0;
+    let mut overlap_start = None;
+    for (position, change) in events {
+        coverage += change;
+        match overlap_start {
+            None if coverage >= 2 => overlap_start = Some(position),
+            Some(first) if coverage < 2 => {
+                overlaps.push((first, position - 1));
+                overlap_start = None;
+            }
+            _ => {}
+        }
+    }
+    overlaps
+}
+
+/// Day 5: Hydrothermal Venture
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 5;
+    type Input = Vec<(Point, Point)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(part2(input))
+    }
+
+    fn variants() -> Vec<(&'static str, Part<Self>)> {
+        vec![
+            (
+                "analytic",
+                Part::One(|input: &Self::Input| Ok(part1_analytic(input))),
+            ),
+            (
+                "analytic",
+                Part::Two(|input: &Self::Input| Ok(part2_analytic(input))),
+            ),
+        ]
+    }
+
+    fn render(input: &Self::Input) -> Option<Box<dyn Render>> {
+        Some(Box::new(VentMap::new(input, true)))
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples/y2021/day05.rs"));
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(5, part1(&values));
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(12, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_windows_style() -> Result<()> {
+    let input = windows_style(EXAMPLE_1);
+    let values = parse(NormalizedInput::new(&input).as_str())?;
+    assert_eq!(5, part1(&values));
+    assert_eq!(12, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_windows_style_error() {
+    let input = NormalizedInput::new("\u{feff}0,9 -> 5,9  \r\n8,0 -> 0;8\r\n");
+    let err = parse(input.as_str())
+        .err()
+        .map(|err| input.original_error(err));
+    assert_eq!(Some(ParseError::new(5, 2, 11, "`,`")), err);
+}
+
+#[test]
+fn test_vent_map_diagrams() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(
+        format!("{}\n", EXAMPLE_2),
+        VentMap::new(&values, false).ascii()?
+    );
+    assert_eq!(
+        format!("{}\n", EXAMPLE_3),
+        VentMap::new(&values, true).ascii()?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_vent_map_queries() -> Result<()> {
+    let map = VentMap::new(&parse(EXAMPLE_1)?, true);
+    assert_eq!(3, map.coverage(Point { x: 4, y: 4 }));
+    assert_eq!(0, map.coverage(Point { x: 100, y: 4 }));
+    assert_eq!(None, map.count_at_least(0));
+    assert_eq!(Some(39), map.count_at_least(1));
+    assert_eq!(Some(12), map.count_at_least(2));
+    assert_eq!(Some(2), map.count_at_least(3));
+    assert_eq!(Some(0), map.count_at_least(4));
+    assert_eq!(
+        vec![(1, 27), (2, 10), (3, 2)],
+        map.histogram().into_iter().collect::<Vec<_>>()
+    );
+
+    // The top two rows, which reach beyond the lines on the right
+    let (min, max) = (Point { x: 0, y: 0 }, Point { x: 20, y: 1 });
+    assert_eq!(Some(1), map.count_at_least_in(min, max, 2));
+    assert_eq!(Some(8), map.count_at_least_in(min, max, 1));
+    assert_eq!(Some(42), map.count_at_least_in(min, max, 0));
+    let all = Point {
+        x: u32::MAX,
+        y: u32::MAX,
+    };
+    assert_eq!(
+        Some(u32::MAX as u64 + 1),
+        map.count_at_least_in(Point { x: 0, y: 0 }, Point { x: 0, y: u32::MAX }, 0)
+    );
+    assert_eq!(None, map.count_at_least_in(Point { x: 0, y: 0 }, all, 0));
+    assert_eq!(
+        map.count_at_least(2),
+        map.count_at_least_in(Point { x: 0, y: 0 }, all, 2)
+    );
+    assert_eq!(
+        Some((Point { x: 7, y: 1 }, 2)),
+        map.covered_in(min, max).find(|&(_, count)| count == 2)
+    );
+    assert_eq!(
+        Some(0),
+        map.count_at_least_in(Point { x: 10, y: 0 }, Point { x: 20, y: 20 }, 1)
+    );
+    assert_eq!(
+        ".2.\n111\n",
+        map.diagram(Point { x: 6, y: 1 }, Point { x: 8, y: 2 })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_sparse_vent_map_queries() -> Result<()> {
+    let input = "0,0 -> 0,2\n0,1 -> 2,1\n9000000,9000000 -> 9000000,9000000";
+    let map = VentMap::new(&parse(input)?, true);
+    assert!(map.is_sparse());
+    assert_eq!(Some(1), map.count_at_least(2));
+    assert_eq!(
+        vec![(1, 5), (2, 1)],
+        map.histogram().into_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some(2),
+        map.count_at_least_in(Point { x: 0, y: 1 }, Point { x: 1, y: 1 }, 1)
+    );
+    assert_eq!(
+        ".\n1\n",
+        map.diagram(Point { x: 1, y: 0 }, Point { x: 1, y: 1 })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_heavy_overlap() -> Result<()> {
+    // More lines through a point than fit into a byte
+    let input = vec!["0,0 -> 2,2"; 300].join("\n");
+    let map = VentMap::new(&parse(&input)?, true);
+    assert_eq!(300, map.coverage(Point { x: 1, y: 1 }));
+    assert_eq!(Some(3), map.count_at_least(2));
+    Ok(())
+}
+
+#[test]
+fn test_large_coordinates() -> Result<()> {
+    let input = "0,0 -> 0,5\n5000000,4000000 -> 5000003,4000003\n0,3 -> 2,3\n5000000,4000003 -> 5000003,4000000";
+    let values = parse(input)?;
+    let map = VentMap::new(&values, true);
+    assert!(map.is_sparse());
+    assert_eq!(2, map.coverage(Point { x: 0, y: 3 }));
+    assert_eq!(
+        0,
+        map.coverage(Point {
+            x: 5000001,
+            y: 4000000
+        })
+    );
+    assert_eq!(1, part1(&values));
+    assert_eq!(1, part2(&values));
+
+    // The bounding box of close lines is stored densely, even away from the origin
+    let map = VentMap::new(
+        &parse("3000000,5 -> 3000009,5\n3000009,0 -> 3000009,9")?,
+        true,
+    );
+    assert!(!map.is_sparse());
+    assert_eq!(2, map.coverage(Point { x: 3000009, y: 5 }));
+    assert_eq!(
+        Some(0),
+        map.count_at_least_in(Point { x: 0, y: 0 }, Point { x: 5, y: 5 }, 1)
+    );
+    assert_eq!(0, map.coverage(Point { x: 0, y: 0 }));
+    // Drawing starts at the bounding box instead of the origin
+    let ascii = map.ascii()?;
+    assert_eq!(10, ascii.lines().count());
+    assert_eq!(".........1", ascii.lines().next().unwrap());
+    assert_eq!("1111111112", ascii.lines().nth(5).unwrap());
+    Ok(())
+}
+
+#[test]
+fn test_full_coordinate_range() -> Result<()> {
+    let input = "0,0 -> 0,0\n4294967295,4294967295 -> 4294967295,4294967295";
+    let map = VentMap::new(&parse(input)?, true);
+    assert!(map.is_sparse());
+    assert_eq!(
+        1,
+        map.coverage(Point {
+            x: u32::MAX,
+            y: u32::MAX
+        })
+    );
+    assert_eq!(0, part2(&parse(input)?));
+    assert!(map.ascii().is_err());
+    assert!(map.image().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_any_slope() -> Result<()> {
+    let values = parse("0,0 -> 4,2\n2,0 -> 2,3\n6,3 -> 0,0\n1,1 -> 1,1")?;
+    assert_eq!(
+        vec![(0, 0), (2, 1), (4, 2)],
+        line_points(&values[0]).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(6, 3), (4, 2), (2, 1), (0, 0)],
+        line_points(&values[2]).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![(1, 1)], line_points(&values[3]).collect::<Vec<_>>());
+    assert_eq!(0, part1(&values));
+    // `0,0`, `2,1` and `4,2` are on both sloped lines, which skip the points in between
+    assert_eq!(3, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_parse_strict() -> Result<()> {
+    assert_eq!(10, parse_strict(EXAMPLE_1)?.len());
+    let err = parse_strict("0,9 -> 5,9\n0,0 -> 4,2").err();
+    assert_eq!(
+        Some(ParseError::new(
+            5,
+            2,
+            1,
+            "a horizontal, vertical or 45 degree line"
+        )),
+        err
+    );
+    Ok(())
+}
+
+#[test]
+fn test_analytic() -> Result<()> {
+    let values = parse(EXAMPLE_1)?;
+    assert_eq!(5, part1_analytic(&values));
+    assert_eq!(12, part2_analytic(&values));
+    Ok(())
+}
+
+#[test]
+fn test_analytic_huge_coordinates() -> Result<()> {
+    // Both diagonals cross inside the overlap of the first and the last line
+    let input =
+        "0,0 -> 4000000000,4000000000\n4000000000,0 -> 0,4000000000\n0,0 -> 2000000000,2000000000";
+    assert_eq!(2000000001, part2_analytic(&parse(input)?));
+    // `6,6` is on two lines and two single points, `6,3` and `2,2` on two lines each, and `4.5,4.5` is no point
+    let input = "0,0 -> 9,9\n0,9 -> 9,0\n6,0 -> 6,9\n6,6 -> 6,6\n6,6 -> 6,6\n2,2 -> 4,3";
+    assert_eq!(3, part2_analytic(&parse(input)?));
+    Ok(())
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_analytic_agrees(
+        lines in proptest::collection::vec((0_u32..12, 0_u32..12, 0_u32..12, 0_u32..12), 1..20),
+    ) {
+        let values: Vec<(Point, Point)> = lines
+            .into_iter()
+            .map(|(x1, y1, x2, y2)| (Point { x: x1, y: y1 }, Point { x: x2, y: y2 }))
+            .collect();
+        proptest::prop_assert_eq!(part1(&values), part1_analytic(&values));
+        proptest::prop_assert_eq!(part2(&values), part2_analytic(&values));
+    }
+}
+
+#[test]
+fn test_parse_error() {
+    let err = parse("0,9 -> 5,9\n8,0 -> 0;8").err();
+    assert_eq!(Some(ParseError::new(5, 2, 11, "`,`")), err);
+    let err = parse("0,9 -> 5,9\n8,0 -> 0,x").err();
+    assert_eq!(Some(ParseError::new(5, 2, 10, "a coordinate")), err);
+}
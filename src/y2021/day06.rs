@@ -66,18 +66,23 @@
 //!
 //! _How many lanternfish would there be after 256 days?_
 
+#[cfg(test)]
+use crate::inputs::{windows_style, NormalizedInput};
 use crate::prelude::*;
 
-/// Fish can have a counter between 0 and 8 (inclusive)
+/// Number of fish for each counter value, as fish can have a counter between 0 and 8 (inclusive)
 #[derive(Debug, Default, Copy, Clone)]
-struct State([u64; 9]);
+pub struct State(pub [u64; 9]);
 
-#[aoc_runner_derive::aoc_generator(day6)]
-fn input_generator(input: &str) -> Result<State> {
+/// Parse the timers of the lanternfish into the number of fish per timer
+pub fn parse(input: &str) -> Result<State, ParseError> {
     let mut state = State::default();
     for c in input.split(',') {
-        let c = usize::from_str(c)?;
-        state.0[c] += 1;
+        let counter = usize::from_str(c)
+            .ok()
+            .filter(|&counter| counter < state.0.len())
+            .ok_or_else(|| ParseError::at(6, input, c, "a timer value between 0 and 8"))?;
+        state.0[counter] += 1;
     }
     Ok(state)
 }
@@ -99,26 +104,48 @@ fn simulate_days(state: &mut State, days: usize) {
     }
 }
 
-#[aoc_runner_derive::aoc(day6, part1)]
-fn part1(input: &State) -> u64 {
+/// Number of fish after 80 days
+pub fn part1(input: &State) -> u64 {
     let mut input = *input;
     simulate_days(&mut input, 80);
     input.0.iter().sum()
 }
 
-#[aoc_runner_derive::aoc(day6, part2)]
-fn part2(input: &State) -> u64 {
+/// Number of fish after 256 days
+pub fn part2(input: &State) -> u64 {
     let mut input = *input;
     simulate_days(&mut input, 256);
     input.0.iter().sum()
 }
 
-#[cfg(test)]
-static TEST_INPUT_1: &str = r"3,4,3,1,2";
+/// Day 6: Lanternfish
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 6;
+    type Input = State;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64> {
+        Ok(part2(input))
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples/y2021/day06.rs"));
 
 #[test]
 fn test_part1_26_days() -> Result<()> {
-    let mut values = input_generator(TEST_INPUT_1)?;
+    let mut values = parse(EXAMPLE_1)?;
     simulate_days(&mut values, 18);
     assert_eq!(26_u64, values.0.iter().sum());
     Ok(())
@@ -126,30 +153,25 @@ fn test_part1_26_days() -> Result<()> {
 
 #[test]
 fn test_part1_80_days() -> Result<()> {
-    let mut values = input_generator(TEST_INPUT_1)?;
+    let mut values = parse(EXAMPLE_1)?;
     simulate_days(&mut values, 80);
     assert_eq!(5934_u64, values.0.iter().sum());
     Ok(())
 }
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day6.txt").trim())?;
-    assert_eq!(365862, part1(&values));
-    Ok(())
-}
-
 #[test]
 fn test_part2_256_days() -> Result<()> {
-    let mut values = input_generator(TEST_INPUT_1)?;
+    let mut values = parse(EXAMPLE_1)?;
     simulate_days(&mut values, 256);
     assert_eq!(26984457539_u64, values.0.iter().sum());
     Ok(())
 }
 
 #[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day6.txt").trim())?;
-    assert_eq!(1653250886439, part2(&values));
+fn test_windows_style() -> Result<()> {
+    let input = windows_style(EXAMPLE_1);
+    let values = parse(NormalizedInput::new(&input).as_str())?;
+    assert_eq!(5934, part1(&values));
+    assert_eq!(26984457539, part2(&values));
     Ok(())
 }
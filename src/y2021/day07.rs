@@ -0,0 +1,164 @@
+//! # Day 7: The Treachery of Whales
+
+#[cfg(test)]
+use crate::inputs::{windows_style, NormalizedInput};
+use crate::prelude::*;
+
+/// Parse the horizontal positions of the crabs, sorted from left to right
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut positions = input
+        .split(',')
+        .map(|v| u64::from_str(v).map_err(|_| ParseError::at(7, input, v, "a position")))
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+    positions.sort_unstable();
+    Ok(positions)
+}
+
+/// Fuel needed to move all crabs to `target`, where moving a crab by `diff` steps costs `cost(diff)`
+///
+/// Positions can be far apart, so this fails instead of overflowing.
+fn fuel(positions: &[u64], target: u64, cost: impl Fn(u64) -> Option<u64>) -> Result<u64> {
+    positions
+        .iter()
+        .try_fold(0_u64, |sum, &pos| {
+            sum.checked_add(cost(pos.abs_diff(target))?)
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Aligning the crabs at {} needs more than {} fuel",
+                target,
+                u64::MAX
+            )
+        })
+}
+
+/// Fuel for a distance, when each further step costs one more fuel
+fn triangle(diff: u64) -> Option<u64> {
+    let diff = diff as u128;
+    u64::try_from(diff * (diff + 1) / 2).ok()
+}
+
+/// Least fuel needed to align all crabs, when each step costs one fuel
+pub fn part1(input: &[u64]) -> Result<u64> {
+    // Moving past the median moves away from at least as many crabs as it moves towards
+    fuel(input, input[input.len() / 2], Some)
+}
+
+/// Same as [`part1`], but computes the cost of aligning at each crab from scratch
+pub fn part1_naive(input: &[u64]) -> Result<u64> {
+    input
+        .iter()
+        .map(|&target| fuel(input, target, Some))
+        .fold_ok(u64::MAX, u64::min)
+}
+
+/// Least fuel needed to align all crabs, when each further step costs one more fuel
+pub fn part2(input: &[u64]) -> Result<u64> {
+    // The total cost is convex and its real minimum lies within half a step of the mean
+    let sum: u128 = input.iter().map(|&pos| pos as u128).sum();
+    let mean = (sum / input.len() as u128) as u64;
+    let (first, last) = (input[0], input[input.len() - 1]);
+    (mean.saturating_sub(1).max(first)..=mean.saturating_add(2).min(last))
+        .map(|target| fuel(input, target, triangle))
+        .fold_ok(u64::MAX, u64::min)
+}
+
+/// Day 7: The Treachery of Whales
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 7;
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64> {
+        part2(input)
+    }
+
+    fn variants() -> Vec<(&'static str, Part<Self>)> {
+        vec![("naive", Part::One(|input: &Self::Input| part1_naive(input)))]
+    }
+}
+
+#[cfg(test)]
+static TEST_INPUT_1: &str = "16,1,2,0,4,2,7,1,2,14";
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(37, part1(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_part1_naive() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(37, part1_naive(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(168, part2(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_windows_style() -> Result<()> {
+    let input = windows_style(TEST_INPUT_1);
+    let values = parse(NormalizedInput::new(&input).as_str())?;
+    assert_eq!(37, part1(&values)?);
+    assert_eq!(168, part2(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        Some(ParseError::new(7, 1, 3, "a position")),
+        parse("0,18446744073709551616").err()
+    );
+    assert_eq!(
+        Some(ParseError::new(7, 1, 3, "a position")),
+        parse("1,-2").err()
+    );
+}
+
+#[test]
+fn test_large_positions() -> Result<()> {
+    let values = parse("1000000000016,1000000000001,1000000000002,1000000000000")?;
+    assert_eq!(17, part1(&values)?);
+    assert_eq!(2_500_050_000, part2(&parse("0,100000")?)?);
+    assert!(part1(&parse("0,18446744073709551615,18446744073709551615")?).is_ok());
+    assert!(part2(&parse("0,18446744073709551615")?).is_err());
+    Ok(())
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_part1_variants_agree(positions in proptest::collection::vec(0_u64..100, 1..100)) {
+        let input = positions.iter().map(|pos| pos.to_string()).join(",");
+        let values = parse(&input).unwrap();
+        proptest::prop_assert_eq!(part1_naive(&values).unwrap(), part1(&values).unwrap());
+    }
+
+    #[test]
+    fn test_part2_cheapest(positions in proptest::collection::vec(0_u64..100, 1..100)) {
+        let input = positions.iter().map(|pos| pos.to_string()).join(",");
+        let values = parse(&input).unwrap();
+        let cheapest = (0..100).map(|target| fuel(&values, target, triangle).unwrap()).min();
+        proptest::prop_assert_eq!(cheapest, Some(part2(&values).unwrap()));
+    }
+}
@@ -1,9 +1,13 @@
-/// TODO Copy task description here
+//! # Day 8: Seven Segment Search
+
 use crate::prelude::*;
 
-struct Input {
-    samples: [SegmentDisplay; 10],
-    digits: [SegmentDisplay; 4],
+/// An entry of the notes
+pub struct Input {
+    /// The ten unique signal patterns
+    pub samples: [SegmentDisplay; 10],
+    /// The four digits of the output value
+    pub digits: [SegmentDisplay; 4],
 }
 
 impl Input {
@@ -83,11 +87,13 @@ impl Input {
     }
 }
 
+/// The segments `a` to `g` which are lit on a seven-segment display
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct SegmentDisplay([bool; 7]);
+pub struct SegmentDisplay([bool; 7]);
 
 impl SegmentDisplay {
-    fn segment_count(&self) -> usize {
+    /// Number of lit segments
+    pub fn segment_count(&self) -> usize {
         self.0.iter().filter(|&&b| b).count()
     }
 }
@@ -124,45 +130,39 @@ impl std::ops::BitAnd for SegmentDisplay {
     }
 }
 
-#[aoc_runner_derive::aoc_generator(day8)]
-fn input_generator(input: &str) -> Result<Vec<Input>> {
+/// Parse the entries, with the signal patterns and output digits separated by `|`
+pub fn parse(input: &str) -> Result<Vec<Input>, ParseError> {
+    /// Parse a space separated list of exactly `N` segment displays
+    fn parse_displays<const N: usize>(
+        input: &str,
+        part: &str,
+    ) -> Result<[SegmentDisplay; N], ParseError> {
+        part.split(' ')
+            .map(|s| {
+                SegmentDisplay::from_str(s)
+                    .map_err(|_| ParseError::at(8, input, s, "a combination of the segments a-g"))
+            })
+            .collect::<Result<Vec<SegmentDisplay>, _>>()?
+            .try_into()
+            .map_err(|_| ParseError::at(8, input, part, format!("{} segment patterns", N)))
+    }
+
     input
         .lines()
         .map(|line| {
-            let mut parts = line.split(" | ");
-            let samples = parts
-                .next()
-                .unwrap()
-                .split(' ')
-                .map(|s| SegmentDisplay::from_str(s))
-                .collect::<Result<Vec<SegmentDisplay>>>()?
-                .try_into()
-                .map_err(|err: Vec<SegmentDisplay>| {
-                    anyhow::anyhow!(
-                        "Failed to create array, because input Vec is only length {}",
-                        err.len()
-                    )
-                })?;
-            let digits = parts
-                .next()
-                .unwrap()
-                .split(' ')
-                .map(|s| SegmentDisplay::from_str(s))
-                .collect::<Result<Vec<SegmentDisplay>>>()?
-                .try_into()
-                .map_err(|err: Vec<SegmentDisplay>| {
-                    anyhow::anyhow!(
-                        "Failed to create array, because input Vec is only length {}",
-                        err.len()
-                    )
-                })?;
-            Ok(Input { samples, digits })
+            let (samples, digits) = line
+                .split_once(" | ")
+                .ok_or_else(|| ParseError::at(8, input, &line[line.len()..], "` | `"))?;
+            Ok(Input {
+                samples: parse_displays(input, samples)?,
+                digits: parse_displays(input, digits)?,
+            })
         })
         .collect()
 }
 
-#[aoc_runner_derive::aoc(day8, part1)]
-fn part1(input: &[Input]) -> usize {
+/// Count the output digits 1, 4, 7 and 8, which are identified by their number of segments
+pub fn part1(input: &[Input]) -> usize {
     input
         .iter()
         .flat_map(|i| i.digits.iter())
@@ -170,8 +170,8 @@ fn part1(input: &[Input]) -> usize {
         .count()
 }
 
-#[aoc_runner_derive::aoc(day8, part2)]
-fn part2(input: &[Input]) -> usize {
+/// Sum of all decoded output values
+pub fn part2(input: &[Input]) -> usize {
     input
         .iter()
         .map(|i| {
@@ -184,6 +184,29 @@ fn part2(input: &[Input]) -> usize {
         .sum()
 }
 
+/// Day 8: Seven Segment Search
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 8;
+    type Input = Vec<Input>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
 #[cfg(test)]
 static TEST_INPUT_1: &str = r"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
 edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
@@ -198,28 +221,14 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
 
 #[test]
 fn test_part1() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
+    let values = parse(TEST_INPUT_1)?;
     assert_eq!(26, part1(&values));
     Ok(())
 }
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day8.txt").trim())?;
-    assert_eq!(344, part1(&values));
-    Ok(())
-}
-
 #[test]
 fn test_part2() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
+    let values = parse(TEST_INPUT_1)?;
     assert_eq!(61229, part2(&values));
     Ok(())
 }
-
-#[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day8.txt").trim())?;
-    assert_eq!(1048410, part2(&values));
-    Ok(())
-}
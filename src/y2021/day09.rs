@@ -0,0 +1,189 @@
+//! # Day 9: Smoke Basin
+
+use crate::prelude::*;
+
+/// Parse the heightmap
+pub fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::from_digits(input, 9)
+}
+
+/// Sum of the risk levels of all low points
+pub fn part1(input: &Grid<u32>) -> u32 {
+    input
+        .positions()
+        .filter_map(|pos| {
+            let height = input[pos];
+            let is_low_point = input
+                .neighbors8(pos)
+                .all(|neighbor| height <= input[neighbor]);
+            is_low_point.then(|| height + 1)
+        })
+        .sum()
+}
+
+/// Product of the sizes of the three largest basins
+pub fn part2(input: &Grid<u32>) -> u32 {
+    let mut input = input.clone();
+    let mut basin_sizes = Vec::new();
+
+    for pos in input.positions() {
+        if input[pos] == 9 {
+            continue;
+        }
+
+        let mut basin_size = 0;
+        let mut unvisited_points = vec![pos];
+        while let Some(pos) = unvisited_points.pop() {
+            if input[pos] == 9 {
+                continue;
+            }
+            basin_size += 1;
+            input[pos] = 9;
+            unvisited_points.extend(input.neighbors4(pos));
+        }
+        basin_sizes.push(basin_size);
+    }
+
+    basin_sizes.sort_by_key(|&x| std::cmp::Reverse(x));
+    basin_sizes.iter().take(3).cloned().product()
+}
+
+/// Heightmap split into basins, which are separated by locations of height 9
+pub struct Basins {
+    heights: Grid<u32>,
+    /// Index of the basin of each location, `None` for height 9
+    basins: Grid<Option<usize>>,
+    /// Indices of the three largest basins, the largest first
+    largest: Vec<usize>,
+}
+
+impl Basins {
+    pub fn new(heights: &Grid<u32>) -> Self {
+        let mut basins = heights.map(|_| None);
+        let mut sizes = Vec::new();
+        for pos in heights.positions() {
+            if heights[pos] == 9 || basins[pos].is_some() {
+                continue;
+            }
+            let basin = sizes.len();
+            let mut size = 0;
+            let mut unvisited_points = vec![pos];
+            while let Some(pos) = unvisited_points.pop() {
+                if heights[pos] == 9 || basins[pos].is_some() {
+                    continue;
+                }
+                basins[pos] = Some(basin);
+                size += 1;
+                unvisited_points.extend(heights.neighbors4(pos));
+            }
+            sizes.push(size);
+        }
+
+        let mut largest: Vec<usize> = (0..sizes.len()).collect();
+        largest.sort_by_key(|&basin| std::cmp::Reverse(sizes[basin]));
+        largest.truncate(3);
+        Self {
+            heights: heights.clone(),
+            basins,
+            largest,
+        }
+    }
+}
+
+impl Render for Basins {
+    /// `#` for height 9, the heights in the three largest basins and `.` in all others
+    fn ascii(&self) -> Result<String> {
+        let mut chars = self.basins.map(|basin| match basin {
+            None => '#',
+            Some(_) => '.',
+        });
+        for pos in self.heights.positions() {
+            if matches!(self.basins[pos], Some(basin) if self.largest.contains(&basin)) {
+                chars[pos] = char::from_digit(self.heights[pos], 10).unwrap_or('?');
+            }
+        }
+        Ok(chars.to_string())
+    }
+
+    /// Black for height 9, the three largest basins in red, green and blue and all others in gray, brighter where they are lower
+    fn image(&self) -> Result<Image> {
+        let mut pixels = Grid::new(self.heights.width(), self.heights.height(), [0; 3]);
+        for pos in self.heights.positions() {
+            let brightness = 255 - (self.heights[pos].min(9) * 20) as u8;
+            pixels[pos] = match self.basins[pos] {
+                None => [0, 0, 0],
+                Some(basin) => match self.largest.iter().position(|&large| large == basin) {
+                    Some(0) => [brightness, 0, 0],
+                    Some(1) => [0, brightness, 0],
+                    Some(_) => [0, 0, brightness],
+                    None => [brightness; 3],
+                },
+            };
+        }
+        Ok(Image::Color(pixels))
+    }
+}
+
+/// Day 9: Smoke Basin
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 9;
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(part2(input))
+    }
+
+    fn render(input: &Self::Input) -> Option<Box<dyn Render>> {
+        Some(Box::new(Basins::new(input)))
+    }
+}
+
+#[cfg(test)]
+static TEST_INPUT_1: &str = r"2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(15, part1(&values));
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(1134, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_basins() -> Result<()> {
+    let basins = Basins::new(&parse(TEST_INPUT_1)?);
+    assert_eq!(
+        "..###43210\n.#878#4#21\n#85678#8#2\n87678#678#\n#8###65678\n",
+        basins.ascii()?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_error() {
+    let err = parse("2199943210\n398789492x").err();
+    assert_eq!(Some(ParseError::new(9, 2, 10, "a digit")), err);
+}
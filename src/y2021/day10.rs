@@ -1,13 +1,9 @@
-/// TODO Copy task description here
-use crate::prelude::*;
+//! # Day 10: Syntax Scoring
 
-// #[aoc_runner_derive::aoc_generator(day10)]
-// fn input_generator(input: &str) -> Result<Vec<u32>> {
-//     todo!()
-// }
+use crate::prelude::*;
 
-#[aoc_runner_derive::aoc(day10, part1)]
-fn part1(input: &str) -> Result<u32> {
+/// Total syntax error score of the corrupted lines
+pub fn part1(input: &str) -> Result<u32> {
     let mut total_penalty = 0;
     for line in input.lines() {
         let mut stack = Vec::new();
@@ -32,8 +28,8 @@ fn part1(input: &str) -> Result<u32> {
     Ok(total_penalty)
 }
 
-#[aoc_runner_derive::aoc(day10, part2)]
-fn part2(input: &str) -> Result<u64> {
+/// Middle score of the completion strings of the incomplete lines
+pub fn part2(input: &str) -> Result<u64> {
     let mut completion_scores = Vec::new();
     'line: for line in input.lines() {
         let mut stack = Vec::new();
@@ -74,6 +70,29 @@ fn part2(input: &str) -> Result<u64> {
     Ok(completion_scores[completion_scores.len() / 2])
 }
 
+/// Day 10: Syntax Scoring
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 10;
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64> {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 static TEST_INPUT_1: &str = r"[({(<(())[]>[[{[]{<()<>>
 [(()[<>])]({[<{<<[]>>(
@@ -92,26 +111,8 @@ fn test_part1() -> Result<()> {
     Ok(())
 }
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    assert_eq!(
-        344193,
-        part1(include_str!("../input/2021/day10.txt").trim())?
-    );
-    Ok(())
-}
-
 #[test]
 fn test_part2() -> Result<()> {
     assert_eq!(288957, part2(TEST_INPUT_1)?);
     Ok(())
 }
-
-#[test]
-fn test_part2_solution() -> Result<()> {
-    assert_eq!(
-        3241238967,
-        part2(include_str!("../input/2021/day10.txt").trim())?
-    );
-    Ok(())
-}
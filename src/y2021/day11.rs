@@ -0,0 +1,191 @@
+//! # Day 11: Dumbo Octopus
+
+use crate::prelude::*;
+
+/// Parse the energy levels of the octopuses
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::from_digits(input, 11)
+}
+
+/// Number of flashes after 100 steps
+pub fn part1(input: &Grid<u8>) -> u32 {
+    let mut input = input.clone();
+    let mut total_flashes = 0;
+    for _ in 0..100 {
+        total_flashes += step(&mut input);
+    }
+    total_flashes
+}
+
+fn step(input: &mut Grid<u8>) -> u32 {
+    let mut total_flashes = 0;
+
+    /// Return the number of flashes triggered by this octopus
+    fn check_octopus(input: &mut Grid<u8>, pos: (usize, usize)) -> u32 {
+        let mut flashes = 0;
+        if input[pos] > 9 {
+            input[pos] = 0;
+            flashes += 1;
+            for neighbor in input.neighbors8(pos) {
+                // Only increment if that octopus hasn't flashed this round yet
+                if input[neighbor] != 0 {
+                    input[neighbor] += 1;
+                    flashes += check_octopus(input, neighbor);
+                }
+            }
+        }
+        flashes
+    }
+
+    // First increment the count for each octopus
+    for octopus in input.iter_mut() {
+        *octopus += 1;
+    }
+
+    // Iterate and flash every octopus greater than 9
+    for pos in input.positions() {
+        total_flashes += check_octopus(input, pos);
+    }
+
+    total_flashes
+}
+
+/// First step during which all octopuses flash
+pub fn part2(input: &Grid<u8>) -> u32 {
+    let mut input = input.clone();
+    for i in 1.. {
+        if step(&mut input) as usize == input.len() {
+            return i;
+        }
+    }
+    unreachable!()
+}
+
+/// Energy levels of the octopuses after a number of steps
+pub struct Octopuses(pub Grid<u8>);
+
+impl Octopuses {
+    pub fn after_steps(input: &Grid<u8>, steps: usize) -> Self {
+        let mut energy = input.clone();
+        for _ in 0..steps {
+            step(&mut energy);
+        }
+        Self(energy)
+    }
+}
+
+impl Render for Octopuses {
+    /// The energy level of each octopus, which is 0 if it flashed during the last step
+    fn ascii(&self) -> Result<String> {
+        Ok(self.0.to_string())
+    }
+
+    /// White for the octopuses which flashed during the last step, otherwise brighter for more energy
+    fn image(&self) -> Result<Image> {
+        Ok(Image::Gray(self.0.map(|&energy| {
+            if energy == 0 {
+                255
+            } else {
+                energy * 20
+            }
+        })))
+    }
+}
+
+/// Day 11: Dumbo Octopus
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 11;
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(part2(input))
+    }
+
+    /// The octopuses after the 100 steps of part 1
+    fn render(input: &Self::Input) -> Option<Box<dyn Render>> {
+        Some(Box::new(Octopuses::after_steps(input, 100)))
+    }
+}
+
+#[cfg(test)]
+static TEST_INPUT_1: &str = r"5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(1656, part1(&values));
+    Ok(())
+}
+
+#[test]
+fn test_part1_10steps() -> Result<()> {
+    let mut values = parse(TEST_INPUT_1)?;
+    let flashes = step(&mut values);
+    assert_eq!(0, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(35, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(45, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(16, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(8, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(1, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(7, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(24, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(39, flashes);
+    let flashes = step(&mut values);
+    assert_eq!(29, flashes);
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(195, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_octopuses() -> Result<()> {
+    let octopuses = Octopuses::after_steps(&parse(TEST_INPUT_1)?, 100);
+    let after_step_100 = r"0397666866
+0749766918
+0053976933
+0004297822
+0004229892
+0053222877
+0532222966
+9322228966
+7922286866
+6789998766
+";
+    assert_eq!(after_step_100, octopuses.ascii()?);
+    Ok(())
+}
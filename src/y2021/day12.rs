@@ -1,13 +1,19 @@
-/// TODO Copy task description here
+//! # Day 12: Passage Pathing
+
 use crate::prelude::*;
 
-#[aoc_runner_derive::aoc_generator(day12)]
-fn input_generator(input: &str) -> Result<Map<String, Vec<String>>> {
-    let values = input.lines().map(|line| -> Result<_> {
-        let mut parts = line.splitn(2, '-');
-        let key = parts.next().unwrap().to_string();
-        let value = parts.next().unwrap().to_string();
-        Ok((key, value))
+/// Parse the connections into the neighbours of each cave
+pub fn parse(input: &str) -> Result<Map<String, Vec<String>>, ParseError> {
+    let values = input.lines().map(|line| {
+        let (key, value) = line
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(12, input, &line[line.len()..], "`-`"))?;
+        for cave in [key, value] {
+            if cave.is_empty() {
+                return Err(ParseError::at(12, input, cave, "a cave name"));
+            }
+        }
+        Ok((key.to_string(), value.to_string()))
     });
     let mut res = Map::new();
     for x in values {
@@ -21,8 +27,8 @@ fn input_generator(input: &str) -> Result<Map<String, Vec<String>>> {
     Ok(res)
 }
 
-#[aoc_runner_derive::aoc(day12, part1)]
-fn part1(input: &Map<String, Vec<String>>) -> usize {
+/// Number of paths from `start` to `end` which visit small caves at most once
+pub fn part1(input: &Map<String, Vec<String>>) -> usize {
     let mut paths = Set::new();
     let mut partial_paths = vec![vec!["start".to_string()]];
 
@@ -49,8 +55,8 @@ fn part1(input: &Map<String, Vec<String>>) -> usize {
     paths.len()
 }
 
-#[aoc_runner_derive::aoc(day12, part2)]
-fn part2(input: &Map<String, Vec<String>>) -> usize {
+/// Number of paths from `start` to `end`, when a single small cave may be visited twice
+pub fn part2(input: &Map<String, Vec<String>>) -> usize {
     let mut paths = Set::new();
     let mut partial_paths = vec![(vec!["start".to_string()], false)];
 
@@ -82,6 +88,29 @@ fn part2(input: &Map<String, Vec<String>>) -> usize {
     paths.len()
 }
 
+/// Day 12: Passage Pathing
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 12;
+    type Input = Map<String, Vec<String>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(part2(input))
+    }
+}
+
 #[cfg(test)]
 static TEST_INPUT_SMALL: &str = r"start-A
 start-b
@@ -125,56 +154,42 @@ start-RW";
 
 #[test]
 fn test_part1_small() -> Result<()> {
-    let values = input_generator(TEST_INPUT_SMALL)?;
+    let values = parse(TEST_INPUT_SMALL)?;
     assert_eq!(10, part1(&values));
     Ok(())
 }
 
 #[test]
 fn test_part1_medium() -> Result<()> {
-    let values = input_generator(TEST_INPUT_MEDIUM)?;
+    let values = parse(TEST_INPUT_MEDIUM)?;
     assert_eq!(19, part1(&values));
     Ok(())
 }
 
 #[test]
 fn test_part1_large() -> Result<()> {
-    let values = input_generator(TEST_INPUT_LARGE)?;
+    let values = parse(TEST_INPUT_LARGE)?;
     assert_eq!(226, part1(&values));
     Ok(())
 }
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day12.txt").trim())?;
-    assert_eq!(4011, part1(&values));
-    Ok(())
-}
-
 #[test]
 fn test_part2_small() -> Result<()> {
-    let values = input_generator(TEST_INPUT_SMALL)?;
+    let values = parse(TEST_INPUT_SMALL)?;
     assert_eq!(36, part2(&values));
     Ok(())
 }
 
 #[test]
 fn test_part2_medium() -> Result<()> {
-    let values = input_generator(TEST_INPUT_MEDIUM)?;
+    let values = parse(TEST_INPUT_MEDIUM)?;
     assert_eq!(103, part2(&values));
     Ok(())
 }
 
 #[test]
 fn test_part2_large() -> Result<()> {
-    let values = input_generator(TEST_INPUT_LARGE)?;
+    let values = parse(TEST_INPUT_LARGE)?;
     assert_eq!(3509, part2(&values));
     Ok(())
 }
-
-#[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day12.txt").trim())?;
-    assert_eq!(108035, part2(&values));
-    Ok(())
-}
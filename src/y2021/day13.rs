@@ -0,0 +1,250 @@
+//! # Day 13: Transparent Origami
+
+#[cfg(test)]
+use crate::inputs::{windows_style, NormalizedInput};
+use crate::prelude::*;
+
+/// Axis along which the paper is folded
+#[derive(Copy, Clone)]
+pub enum Fold {
+    X,
+    Y,
+}
+
+/// Positions of the dots, with the width and height of the paper
+///
+/// Dots can be far apart, so only the dots are stored and not the whole paper.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Dots {
+    pub dots: HashSet<(u32, u32)>,
+    pub width: u64,
+    pub height: u64,
+}
+
+/// Parse the dots on the paper and the fold instructions with the line to fold along
+pub fn parse(input: &str) -> Result<(Dots, Vec<(Fold, u32)>), ParseError> {
+    let (points, folds) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at(13, input, &input[input.len()..], "an empty separator line")
+    })?;
+    let parse_number = |value: &str| {
+        value
+            .parse::<u32>()
+            .map_err(|_| ParseError::at(13, input, value, "a number"))
+    };
+
+    let mut paper = Dots {
+        dots: HashSet::new(),
+        width: 0,
+        height: 0,
+    };
+    for line in points.lines() {
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(13, input, &line[line.len()..], "`,`"))?;
+        let (x, y) = (parse_number(x)?, parse_number(y)?);
+        paper.width = paper.width.max(x as u64 + 1);
+        paper.height = paper.height.max(y as u64 + 1);
+        paper.dots.insert((x, y));
+    }
+
+    let folds = folds
+        .lines()
+        .map(|line| {
+            let (direction, place) = line
+                .strip_prefix("fold along ")
+                .and_then(|fold| fold.split_once('='))
+                .ok_or_else(|| ParseError::at(13, input, line, "`fold along <x|y>=<number>`"))?;
+            let direction = match direction {
+                "x" => Fold::X,
+                "y" => Fold::Y,
+                _ => return Err(ParseError::at(13, input, direction, "`x` or `y`")),
+            };
+            Ok((direction, parse_number(place)?))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((paper, folds))
+}
+
+fn fold_dots(paper: &Dots, fold: Fold, place: u32) -> Result<Dots> {
+    let mirror = |pos: u32| {
+        // Mirror everything behind the fold line, the fold line itself disappears
+        match pos.checked_sub(place) {
+            Some(0) => Ok(None),
+            Some(behind) => place.checked_sub(behind).map(Some).ok_or_else(|| {
+                anyhow!(
+                    "Folding along {} moves the dot at {} off the paper",
+                    place,
+                    pos
+                )
+            }),
+            None => Ok(Some(pos)),
+        }
+    };
+    let mut dots = HashSet::new();
+    for &(x, y) in &paper.dots {
+        let new_pos = match fold {
+            Fold::X => mirror(x)?.map(|x| (x, y)),
+            Fold::Y => mirror(y)?.map(|y| (x, y)),
+        };
+        dots.extend(new_pos);
+    }
+    let (width, height) = match fold {
+        Fold::X => (place as u64, paper.height),
+        Fold::Y => (paper.width, place as u64),
+    };
+    Ok(Dots {
+        dots,
+        width,
+        height,
+    })
+}
+
+/// Number of visible dots after the first fold
+pub fn part1((paper, folds): &(Dots, Vec<(Fold, u32)>)) -> Result<usize> {
+    let mut paper = paper.clone();
+
+    for &(direction, place) in folds.iter().take(1) {
+        paper = fold_dots(&paper, direction, place)?;
+    }
+
+    Ok(paper.dots.len())
+}
+
+/// Paper after all folds, which shows eight capital letters
+pub fn part2(input: &(Dots, Vec<(Fold, u32)>)) -> Result<String> {
+    Ok(format!("\n{}", fold_all(input)?.ascii()?))
+}
+
+/// Paper after all folds
+fn fold_all((paper, folds): &(Dots, Vec<(Fold, u32)>)) -> Result<Dots> {
+    let mut paper = paper.clone();
+
+    for &(direction, place) in folds {
+        paper = fold_dots(&paper, direction, place)?;
+    }
+    Ok(paper)
+}
+
+/// The paper drawn as `#` for a dot and `.` for an empty position
+impl Render for Dots {
+    fn ascii(&self) -> Result<String> {
+        let mut grid = usize::try_from(self.width)
+            .ok()
+            .zip(usize::try_from(self.height).ok())
+            .and_then(|(width, height)| Grid::try_new(width, height, '.'))
+            .ok_or_else(|| {
+                anyhow!(
+                    "The paper of {}x{} is too large to draw",
+                    self.width,
+                    self.height
+                )
+            })?;
+        for &(x, y) in &self.dots {
+            grid[(x as usize, y as usize)] = '#';
+        }
+        Ok(grid.to_string())
+    }
+}
+
+/// Day 13: Transparent Origami
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 13;
+    type Input = (Dots, Vec<(Fold, u32)>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<String> {
+        part2(input)
+    }
+
+    fn render(input: &Self::Input) -> Option<Box<dyn Render>> {
+        let paper = fold_all(input).ok()?;
+        Some(Box::new(paper))
+    }
+}
+
+#[cfg(test)]
+static TEST_INPUT_1: &str = r"6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(17, part1(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    let solution = r"
+#####
+#...#
+#...#
+#...#
+#####
+.....
+.....
+";
+    assert_eq!(solution, part2(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_windows_style() -> Result<()> {
+    let input = windows_style(TEST_INPUT_1);
+    let values = parse(NormalizedInput::new(&input).as_str())?;
+    assert_eq!(17, part1(&values)?);
+    assert!(part2(&values)?.starts_with("\n#####\n#...#\n"));
+    Ok(())
+}
+
+#[test]
+fn test_parse_error() {
+    let err = parse("6,10\n0,14\n\nfold along y=7\nfold along z=5").err();
+    assert_eq!(Some(ParseError::new(13, 5, 12, "`x` or `y`")), err);
+    let err = parse("6,10\n0,x\n\nfold along y=7").err();
+    assert_eq!(Some(ParseError::new(13, 2, 3, "a number")), err);
+    let err = parse("6,10\n0,4294967296\n\nfold along y=7").err();
+    assert_eq!(Some(ParseError::new(13, 2, 3, "a number")), err);
+}
+
+#[test]
+fn test_large_coordinates() -> Result<()> {
+    let values = parse("0,0\n70000,70000\n\nfold along x=35000\nfold along y=35000")?;
+    assert_eq!(2, part1(&values)?);
+    // Folding in the left half moves the right edge of the paper past its left edge
+    let values = parse("0,0\n70000,70000\n\nfold along x=1")?;
+    assert!(part1(&values).is_err());
+    Ok(())
+}
@@ -1,17 +1,24 @@
-/// TODO Copy task description here
+//! # Day 14: Extended Polymerization
+
+#[cfg(test)]
+use crate::inputs::{windows_style, NormalizedInput};
 use crate::prelude::*;
-use anyhow::anyhow;
 
-struct Input {
-    polymer_template: Vec<char>,
-    pair_insertions: Map<(char, char), char>,
+/// The polymer template and the pair insertion rules
+pub struct Input {
+    pub polymer_template: Vec<char>,
+    /// Element inserted between each pair of elements
+    pub pair_insertions: Map<(char, char), char>,
 }
 
-#[aoc_runner_derive::aoc_generator(day14)]
-fn input_generator(input: &str) -> Result<Input> {
-    let (template, insertions) = input
-        .split_once("\n\n")
-        .ok_or_else(|| anyhow!("Missing delimiter"))?;
+/// Parse the polymer template and the pair insertion rules
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let (template, insertions) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::at(14, input, &input[input.len()..], "an empty separator line")
+    })?;
+    if template.is_empty() {
+        return Err(ParseError::at(14, input, template, "a polymer template"));
+    }
     Ok(Input {
         polymer_template: template.chars().collect(),
         pair_insertions: insertions
@@ -19,17 +26,18 @@ fn input_generator(input: &str) -> Result<Input> {
             .map(|line| {
                 let (pair, insertion) = line
                     .split_once(" -> ")
-                    .ok_or_else(|| anyhow!("Missing delimiter"))?;
-                let mut pair = pair.chars();
-                let pair_a = pair.next().ok_or_else(|| anyhow!("Missing char"))?;
-                let pair_b = pair.next().ok_or_else(|| anyhow!("Missing char"))?;
+                    .ok_or_else(|| ParseError::at(14, input, &line[line.len()..], "` -> `"))?;
+                let (pair_a, pair_b) = pair
+                    .chars()
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::at(14, input, pair, "a pair of two elements"))?;
                 let insertion = insertion
                     .chars()
-                    .next()
-                    .ok_or_else(|| anyhow!("Missing char"))?;
+                    .exactly_one()
+                    .map_err(|_| ParseError::at(14, input, insertion, "a single element"))?;
                 Ok(((pair_a, pair_b), insertion))
             })
-            .collect::<Result<_>>()?,
+            .collect::<Result<_, _>>()?,
     })
 }
 
@@ -93,8 +101,8 @@ fn quantity_diff_pairs(polymer_pairs: &Map<(char, char), usize>) -> usize {
     counts[counts.len() - 1] - counts[0]
 }
 
-#[aoc_runner_derive::aoc(day14, part1, sequence)]
-fn part1_sequence(input: &Input) -> usize {
+/// Difference between the most and least common element after 10 steps, building the whole polymer
+pub fn part1_sequence(input: &Input) -> usize {
     let mut polymer_template = input.polymer_template.clone();
     for _ in 0..10 {
         polymer_template = insert_polymers(&polymer_template, &input.pair_insertions);
@@ -102,8 +110,8 @@ fn part1_sequence(input: &Input) -> usize {
     quantity_diff(&polymer_template)
 }
 
-#[aoc_runner_derive::aoc(day14, part1, pairs)]
-fn part1_pairs(input: &Input) -> usize {
+/// Same as [`part1_sequence`], but only counts the pairs of elements
+pub fn part1_pairs(input: &Input) -> usize {
     let mut polymer_pairs = Map::new();
     for pair in input
         .polymer_template
@@ -126,8 +134,8 @@ fn part1_pairs(input: &Input) -> usize {
     quantity_diff_pairs(&polymer_pairs)
 }
 
-#[aoc_runner_derive::aoc(day14, part2)]
-fn part2(input: &Input) -> usize {
+/// Difference between the most and least common element after 40 steps
+pub fn part2(input: &Input) -> usize {
     let mut polymer_pairs = Map::new();
     for pair in input
         .polymer_template
@@ -150,6 +158,43 @@ fn part2(input: &Input) -> usize {
     quantity_diff_pairs(&polymer_pairs)
 }
 
+/// Day 14: Extended Polymerization
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 14;
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(part1_pairs(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(part2(input))
+    }
+
+    fn variants() -> Vec<(&'static str, Part<Self>)> {
+        // Part 1 counts pairs by default, but both ways can be selected by name
+        vec![
+            (
+                "pairs",
+                Part::One(|input: &Self::Input| Ok(part1_pairs(input))),
+            ),
+            (
+                "sequence",
+                Part::One(|input: &Self::Input| Ok(part1_sequence(input))),
+            ),
+        ]
+    }
+}
+
 #[cfg(test)]
 static TEST_INPUT_1: &str = r"NNCB
 
@@ -172,35 +217,50 @@ CN -> C";
 
 #[test]
 fn test_part1_sequence() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
+    let values = parse(TEST_INPUT_1)?;
     assert_eq!(1588, part1_sequence(&values));
     Ok(())
 }
 
 #[test]
 fn test_part1_pairs() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
+    let values = parse(TEST_INPUT_1)?;
     assert_eq!(1588, part1_pairs(&values));
     Ok(())
 }
 
 #[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day14.txt").trim())?;
-    assert_eq!(2967, part1_sequence(&values));
+fn test_part2() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(2188189693529, part2(&values));
     Ok(())
 }
 
 #[test]
-fn test_part2() -> Result<()> {
-    let values = input_generator(TEST_INPUT_1)?;
+fn test_windows_style() -> Result<()> {
+    let input = windows_style(TEST_INPUT_1);
+    let values = parse(NormalizedInput::new(&input).as_str())?;
+    assert_eq!(1588, part1_pairs(&values));
     assert_eq!(2188189693529, part2(&values));
     Ok(())
 }
 
-// #[test]
-// fn test_part2_solution() -> Result<()> {
-//     let values = input_generator(include_str!("../input/2021/day1.txt").trim())?;
-//     assert_eq!(todo!(), part2(&values)?);
-//     Ok(())
-// }
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_part1_variants_agree(
+        template in "[A-D]{1,10}",
+        insertions in proptest::collection::btree_map("[A-D]{2}", "[A-D]", 0..=16),
+    ) {
+        let input = format!(
+            "{}\n\n{}",
+            template,
+            insertions
+                .iter()
+                .map(|(pair, insertion)| format!("{} -> {}", pair, insertion))
+                .join("\n")
+        );
+        let values = parse(&input).unwrap();
+        proptest::prop_assert_eq!(part1_sequence(&values), part1_pairs(&values));
+    }
+}
@@ -0,0 +1,225 @@
+//! # Day 15: Chiton
+
+use crate::prelude::*;
+use pathfinding::prelude::absdiff;
+#[cfg(test)]
+use proptest::strategy::Strategy as _;
+
+/// Parse the risk level of each position
+///
+/// The path needs a start and an end, so the map must not be empty.
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    let grid = Grid::from_digits(input, 15)?;
+    if grid.width() == 0 {
+        return Err(ParseError::at(15, input, input, "a digit"));
+    }
+    Ok(grid)
+}
+
+/// Lowest total risk of any path from the top left to the bottom right
+pub fn part1(input: &Grid<u8>) -> u32 {
+    let mut openlist = Vec::new();
+
+    let mut costs = Grid::new(input.width(), input.height(), u32::MAX);
+    costs[(0, 0)] = 0;
+
+    openlist.push((0, 0));
+
+    while let Some(pos) = openlist.pop() {
+        // Iterate over all neighbors and find the lowest cost
+        for neigh in input.neighbors4(pos) {
+            let neigh_cost = costs[pos] + input[neigh] as u32;
+            if neigh_cost < costs[neigh] {
+                costs[neigh] = neigh_cost;
+                openlist.push(neigh);
+            }
+        }
+    }
+
+    costs[(input.width() - 1, input.height() - 1)]
+}
+
+/// Same as [`part1`], but searches with A*
+pub fn part1_astar(input: &Grid<u8>) -> u32 {
+    search_astar(input).1
+}
+
+/// Path with the lowest total risk, including the start, and its total risk
+fn search_astar(input: &Grid<u8>) -> (Vec<(usize, usize)>, u32) {
+    let start = (0, 0);
+    let goal = (input.width() - 1, input.height() - 1);
+
+    let solution = pathfinding::directed::astar::astar(
+        &start,
+        |&pos| {
+            input
+                .neighbors4(pos)
+                .map(|neigh| (neigh, input[neigh] as u32))
+        },
+        |&(x, y)| (absdiff(x, goal.0) + absdiff(y, goal.1)) as u32,
+        |&p| p == goal,
+    );
+
+    solution.unwrap()
+}
+
+/// Lowest total risk on the full map, which is the map tiled five times in each direction
+pub fn part2(input: &Grid<u8>) -> u32 {
+    let max_x = input.width();
+    let max_y = input.height();
+
+    // make the input 5 times larger for each dimension
+    let mut larger_input = Grid::new(max_x * 5, max_y * 5, 0);
+    for (x, y) in larger_input.positions() {
+        let mut cost = input[(x % max_x, y % max_y)] + (y / max_y) as u8 + (x / max_x) as u8;
+        while cost > 9 {
+            // 10 wraps around to 1
+            cost -= 9;
+        }
+        larger_input[(x, y)] = cost;
+    }
+
+    log::trace!("Expanded cave:\n{}", larger_input);
+
+    search_astar(&larger_input).1
+}
+
+/// The risk levels with the path of the lowest total risk
+pub struct CavePath {
+    risks: Grid<u8>,
+    path: Vec<(usize, usize)>,
+}
+
+impl CavePath {
+    pub fn lowest_risk(input: &Grid<u8>) -> Self {
+        Self {
+            risks: input.clone(),
+            path: search_astar(input).0,
+        }
+    }
+}
+
+impl Render for CavePath {
+    /// The risk levels on the path and `.` everywhere else
+    fn ascii(&self) -> Result<String> {
+        let mut chars = self.risks.map(|_| '.');
+        for &pos in &self.path {
+            chars[pos] = char::from(b'0' + self.risks[pos]);
+        }
+        Ok(chars.to_string())
+    }
+
+    /// The path in red, everywhere else darker for higher risks
+    fn image(&self) -> Result<Image> {
+        let mut pixels = self.risks.map(|&risk| {
+            let shade = crate::render::shade(risk as u32, 9);
+            [shade; 3]
+        });
+        for &pos in &self.path {
+            pixels[pos] = [255, 0, 0];
+        }
+        Ok(Image::Color(pixels))
+    }
+}
+
+/// Day 15: Chiton
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 15;
+    type Input = Grid<u8>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(part2(input))
+    }
+
+    fn variants() -> Vec<(&'static str, Part<Self>)> {
+        vec![(
+            "astar",
+            Part::One(|input: &Self::Input| Ok(part1_astar(input))),
+        )]
+    }
+
+    /// The path of part 1
+    fn render(input: &Self::Input) -> Option<Box<dyn Render>> {
+        Some(Box::new(CavePath::lowest_risk(input)))
+    }
+}
+
+#[cfg(test)]
+static TEST_INPUT_1: &str = r"1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(40, part1(&values));
+    Ok(())
+}
+
+#[test]
+fn test_part1_astar() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(40, part1_astar(&values));
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(315, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(Some(ParseError::new(15, 1, 1, "a digit")), parse("").err());
+}
+
+#[test]
+fn test_cave_path() -> Result<()> {
+    let path = CavePath::lowest_risk(&parse(TEST_INPUT_1)?);
+    let ascii = path.ascii()?;
+    assert!(ascii.starts_with('1'));
+    assert!(ascii.ends_with("1\n"));
+    let risk: u32 = ascii.chars().filter_map(|c| c.to_digit(10)).sum();
+    // The risk of the start does not count
+    assert_eq!(40 + 1, risk);
+    Ok(())
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn test_part1_variants_agree(
+        rows in (1_usize..15).prop_flat_map(|width| {
+            proptest::collection::vec(proptest::collection::vec(1_u8..=9, width), 1..15)
+        }),
+    ) {
+        let input = rows
+            .iter()
+            .map(|row| row.iter().map(|risk| risk.to_string()).join(""))
+            .join("\n");
+        let values = parse(&input).unwrap();
+        proptest::prop_assert_eq!(part1(&values), part1_astar(&values));
+    }
+}
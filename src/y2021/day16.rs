@@ -1,16 +1,19 @@
-/// TODO Copy task description here
+//! # Day 16: Packet Decoder
+
 use crate::prelude::*;
 #[cfg(test)]
-pub use pretty_assertions::assert_eq;
+use pretty_assertions::assert_eq;
 
+/// A packet of the Buoyancy Interchange Transmission System
 #[derive(Debug, PartialEq, Eq)]
-struct BitsPacket {
-    version: u8,
-    ty: BitsType,
+pub struct BitsPacket {
+    pub version: u8,
+    pub ty: BitsType,
 }
 
+/// Content of a packet, which is either a literal value or an operator on subpackets
 #[derive(Debug, PartialEq, Eq)]
-enum BitsType {
+pub enum BitsType {
     Lit {
         literal: u128,
     },
@@ -20,8 +23,9 @@ enum BitsType {
     },
 }
 
+/// Operation of an operator packet, as given by the packet type ID
 #[derive(Debug, PartialEq, Eq)]
-enum BitsOperatorType {
+pub enum BitsOperatorType {
     Sum = 0,
     Product = 1,
     Minimum = 2,
@@ -50,8 +54,9 @@ impl TryFrom<u8> for BitsOperatorType {
     }
 }
 
+/// Subpackets of an operator packet, with the length type ID they were encoded with
 #[derive(Debug, PartialEq, Eq)]
-enum BitsOperands {
+pub enum BitsOperands {
     LengthDefined {
         length: u16,
         subpackets: Vec<BitsPacket>,
@@ -71,55 +76,89 @@ impl BitsOperands {
     }
 }
 
-#[aoc_runner_derive::aoc_generator(day16)]
-fn input_generator(input: &str) -> Result<BitsPacket> {
+/// Decode the hexadecimal transmission into its outermost packet
+pub fn parse(input: &str) -> Result<BitsPacket, ParseError> {
     // First convert the hex input into a bit vector
-    let bits: Vec<bool> = input
-        .chars()
-        .flat_map(|c| match c {
-            '0' => [false, false, false, false],
-            '1' => [false, false, false, true],
-            '2' => [false, false, true, false],
-            '3' => [false, false, true, true],
-            '4' => [false, true, false, false],
-            '5' => [false, true, false, true],
-            '6' => [false, true, true, false],
-            '7' => [false, true, true, true],
-            '8' => [true, false, false, false],
-            '9' => [true, false, false, true],
-            'A' => [true, false, true, false],
-            'B' => [true, false, true, true],
-            'C' => [true, true, false, false],
-            'D' => [true, true, false, true],
-            'E' => [true, true, true, false],
-            'F' => [true, true, true, true],
-            _ => panic!("Invalid hex character: {}", c),
+    let nibbles = input
+        .char_indices()
+        .map(|(idx, c)| {
+            Ok(match c {
+                '0' => [false, false, false, false],
+                '1' => [false, false, false, true],
+                '2' => [false, false, true, false],
+                '3' => [false, false, true, true],
+                '4' => [false, true, false, false],
+                '5' => [false, true, false, true],
+                '6' => [false, true, true, false],
+                '7' => [false, true, true, true],
+                '8' => [true, false, false, false],
+                '9' => [true, false, false, true],
+                'A' => [true, false, true, false],
+                'B' => [true, false, true, true],
+                'C' => [true, true, false, false],
+                'D' => [true, true, false, true],
+                'E' => [true, true, true, false],
+                'F' => [true, true, true, true],
+                _ => {
+                    return Err(ParseError::at(
+                        16,
+                        input,
+                        &input[idx..],
+                        "a hexadecimal digit",
+                    ))
+                }
+            })
         })
-        .collect();
-
-    let (packet, remaining_bits) = parse_packet(&bits);
+        .collect::<Result<Vec<[bool; 4]>, _>>()?;
+    let bits: Vec<bool> = nibbles.into_iter().flatten().collect();
+
+    // The input is only ASCII at this point, so each character is one byte and holds four bits
+    let error_at =
+        |bit_idx: usize, expected: &str| ParseError::at(16, input, &input[bit_idx / 4..], expected);
+    let (packet, remaining_bits) =
+        parse_packet(&bits).map_err(|err| error_at(bits.len() - err.remaining, err.expected))?;
     // Ensure all remaining bits are 0
-    assert_bits_unset(remaining_bits);
+    if let Some(set_bit) = remaining_bits.iter().position(|&b| b) {
+        let bit_idx = bits.len() - remaining_bits.len() + set_bit;
+        return Err(error_at(
+            bit_idx,
+            "only unset padding bits after the outermost packet",
+        ));
+    }
     Ok(packet)
 }
 
-#[track_caller]
-fn assert_bits_unset(bits: &[bool]) {
-    assert!(
-        bits.iter().all(|b| !b),
-        "The {} bits are not all unset: {:?}",
-        bits.len(),
-        bits
-    );
+/// Malformed packet, which `parse` turns into a [`ParseError`]
+struct BitsError {
+    /// Number of bits after the error, as `parse_packet` only sees the end of the transmission
+    remaining: usize,
+    expected: &'static str,
+}
+
+/// Split off the first `n` bits
+fn take_bits<'a>(
+    bits: &mut &'a [bool],
+    n: usize,
+    expected: &'static str,
+) -> Result<&'a [bool], BitsError> {
+    if bits.len() < n {
+        return Err(BitsError {
+            remaining: bits.len(),
+            expected,
+        });
+    }
+    let (taken, rest) = bits.split_at(n);
+    *bits = rest;
+    Ok(taken)
 }
 
 /// Returns one parsed packets and the unprocessed bits
-fn parse_packet(mut bits: &[bool]) -> (BitsPacket, &[bool]) {
-    let version = bits_to_u8(&bits[..3]);
-    bits = &bits[3..];
+fn parse_packet(mut bits: &[bool]) -> Result<(BitsPacket, &[bool]), BitsError> {
+    let packet_start = bits.len();
+    let version = bits_to_u8(take_bits(&mut bits, 3, "a packet version")?);
 
-    let ty = bits_to_u8(&bits[..3]);
-    bits = &bits[3..];
+    let ty = bits_to_u8(take_bits(&mut bits, 3, "a packet type")?);
+    // Every three bit value is a valid type
     let ty = BitsOperatorType::try_from(ty).unwrap();
 
     let ty = if ty == BitsOperatorType::Literal {
@@ -127,9 +166,7 @@ fn parse_packet(mut bits: &[bool]) -> (BitsPacket, &[bool]) {
         loop {
             // Shift to make space for the next couple of bits read
             literal <<= 4;
-            let literal_bits = &bits[..5];
-            bits = &bits[5..];
-            match literal_bits {
+            match take_bits(&mut bits, 5, "a group of literal bits")? {
                 [true, literal_bits @ ..] => {
                     literal += bits_to_u8(literal_bits) as u128;
                     // First true means we need to continue processing data
@@ -139,42 +176,67 @@ fn parse_packet(mut bits: &[bool]) -> (BitsPacket, &[bool]) {
                     // First false mean it is the end of the literal value
                     break;
                 }
-                _ => panic!("Invalid literal bits: {:?}", bits),
+                [] => unreachable!("take_bits returns exactly five bits"),
             }
         }
         BitsType::Lit { literal }
     } else {
-        let bit_op = bits[0];
-        bits = &bits[1..];
+        let bit_op = take_bits(&mut bits, 1, "a length type ID")?[0];
 
         let op = if bit_op {
-            let count = bits_to_u16(&bits[..11]);
-            bits = &bits[11..];
+            let count = bits_to_u16(take_bits(&mut bits, 11, "a number of subpackets")?);
             let mut subpackets = Vec::new();
             for _ in 0..count {
-                let (packet, remaining_bits) = parse_packet(bits);
+                let (packet, remaining_bits) = parse_packet(bits)?;
                 subpackets.push(packet);
                 bits = remaining_bits;
             }
             BitsOperands::PacketDefined { count, subpackets }
         } else {
-            let length = bits_to_u16(&bits[..15]);
-            bits = &bits[15..];
+            let length = bits_to_u16(take_bits(&mut bits, 15, "a length of subpackets")?);
             let mut subpackets = Vec::new();
-            let mut subpacket_bits = &bits[..length as usize];
-            bits = &bits[length as usize..];
+            let mut subpacket_bits = take_bits(
+                &mut bits,
+                length as usize,
+                "as many subpacket bits as announced",
+            )?;
             while !subpacket_bits.is_empty() {
-                let (subpacket, remaining_bits) = parse_packet(subpacket_bits);
+                // Errors inside the subpackets need to count the bits following them too
+                let (subpacket, remaining_bits) =
+                    parse_packet(subpacket_bits).map_err(|err| BitsError {
+                        remaining: err.remaining + bits.len(),
+                        ..err
+                    })?;
                 subpackets.push(subpacket);
                 subpacket_bits = remaining_bits;
             }
             BitsOperands::LengthDefined { length, subpackets }
         };
 
+        // Ensure the operator can be evaluated
+        let subpacket_count = op.subpacket().len();
+        let expected = match ty {
+            BitsOperatorType::GreaterThan
+            | BitsOperatorType::LessThan
+            | BitsOperatorType::EqualTo
+                if subpacket_count != 2 =>
+            {
+                Some("exactly two subpackets for a comparison")
+            }
+            _ if subpacket_count == 0 => Some("at least one subpacket"),
+            _ => None,
+        };
+        if let Some(expected) = expected {
+            return Err(BitsError {
+                remaining: packet_start,
+                expected,
+            });
+        }
+
         BitsType::Operator { ty, op }
     };
 
-    (BitsPacket { version, ty }, bits)
+    Ok((BitsPacket { version, ty }, bits))
 }
 
 fn bits_to_u8(bits: &[bool]) -> u8 {
@@ -188,8 +250,8 @@ fn bits_to_u16(bits: &[bool]) -> u16 {
         .fold(0, |acc, bit| (acc << 1) + if *bit { 1 } else { 0 })
 }
 
-#[aoc_runner_derive::aoc(day16, part1)]
-fn part1(input: &BitsPacket) -> u32 {
+/// Sum of the version numbers of all packets
+pub fn part1(input: &BitsPacket) -> u32 {
     let mut sum_versions = input.version as _;
 
     sum_versions += match input {
@@ -209,9 +271,11 @@ fn part1(input: &BitsPacket) -> u32 {
     sum_versions
 }
 
-#[aoc_runner_derive::aoc(day16, part2)]
-fn part2(input: &BitsPacket) -> u128 {
-    match input {
+/// Value of the outermost packet
+///
+/// Sums and products of large literals can exceed even 128 bits, which is an error.
+pub fn part2(input: &BitsPacket) -> Result<u128> {
+    Ok(match input {
         // Literals are the easy case
         BitsPacket {
             ty: BitsType::Lit { literal },
@@ -222,11 +286,22 @@ fn part2(input: &BitsPacket) -> u128 {
             ..
         } => {
             // evaluate subpackets
-            let mut subpackets = op.subpacket().iter().map(|p| part2(p));
+            let values = op
+                .subpacket()
+                .iter()
+                .map(part2)
+                .collect::<Result<Vec<u128>>>()?;
+            let mut subpackets = values.into_iter();
 
             match ty {
-                BitsOperatorType::Sum => subpackets.sum(),
-                BitsOperatorType::Product => subpackets.product(),
+                BitsOperatorType::Sum => {
+                    subpackets
+                        .try_fold(0_u128, |acc, v| acc.checked_add(v))
+                        .ok_or_else(|| anyhow!("Sum of a packet exceeds {}", u128::MAX))?
+                }
+                BitsOperatorType::Product => subpackets
+                    .try_fold(1_u128, |acc, v| acc.checked_mul(v))
+                    .ok_or_else(|| anyhow!("Product of a packet exceeds {}", u128::MAX))?,
                 BitsOperatorType::Minimum => subpackets.min().unwrap(),
                 BitsOperatorType::Maximum => subpackets.max().unwrap(),
                 BitsOperatorType::Literal => unreachable!(),
@@ -245,6 +320,29 @@ fn part2(input: &BitsPacket) -> u128 {
                 }
             }
         }
+    })
+}
+
+/// Day 16: Packet Decoder
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 16;
+    type Input = BitsPacket;
+    type Answer1 = u32;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<u128> {
+        part2(input)
     }
 }
 
@@ -267,7 +365,7 @@ static TEST_INPUT_7: &str = r"A0016C880162017C3686B18A3D4780";
 fn test_parse_input1() -> Result<()> {
     use BitsType::*;
 
-    let values = input_generator(TEST_INPUT_1)?;
+    let values = parse(TEST_INPUT_1)?;
     let expected = BitsPacket {
         version: 6,
         ty: Lit { literal: 2021 },
@@ -282,7 +380,7 @@ fn test_parse_input2() -> Result<()> {
     use BitsOperatorType::*;
     use BitsType::*;
 
-    let values = input_generator(TEST_INPUT_2)?;
+    let values = parse(TEST_INPUT_2)?;
     let expected = BitsPacket {
         version: 1,
         ty: Operator {
@@ -312,7 +410,7 @@ fn test_parse_input3() -> Result<()> {
     use BitsOperatorType::*;
     use BitsType::*;
 
-    let values = input_generator(TEST_INPUT_3)?;
+    let values = parse(TEST_INPUT_3)?;
     let expected = BitsPacket {
         version: 7,
         ty: Operator {
@@ -346,7 +444,7 @@ fn test_parse_input4() -> Result<()> {
     use BitsOperatorType::*;
     use BitsType::*;
 
-    let values = input_generator(TEST_INPUT_4)?;
+    let values = parse(TEST_INPUT_4)?;
     let expected = BitsPacket {
         version: 4,
         ty: Operator {
@@ -388,7 +486,7 @@ fn test_parse_input5() -> Result<()> {
     use BitsOperatorType::*;
     use BitsType::*;
 
-    let values = input_generator(TEST_INPUT_5)?;
+    let values = parse(TEST_INPUT_5)?;
     let expected = BitsPacket {
         version: 3,
         ty: Operator {
@@ -448,7 +546,7 @@ fn test_parse_input6() -> Result<()> {
     use BitsOperatorType::*;
     use BitsType::*;
 
-    let values = input_generator(TEST_INPUT_6)?;
+    let values = parse(TEST_INPUT_6)?;
     let expected = BitsPacket {
         version: 6,
         ty: Operator {
@@ -508,7 +606,7 @@ fn test_parse_input7() -> Result<()> {
     use BitsOperatorType::*;
     use BitsType::*;
 
-    let values = input_generator(TEST_INPUT_7)?;
+    let values = parse(TEST_INPUT_7)?;
     let expected = BitsPacket {
         version: 5,
         ty: Operator {
@@ -564,98 +662,153 @@ fn test_parse_input7() -> Result<()> {
 
 #[test]
 fn test_part1_input4() -> Result<()> {
-    let values = input_generator(TEST_INPUT_4)?;
+    let values = parse(TEST_INPUT_4)?;
     assert_eq!(16, part1(&values));
     Ok(())
 }
 
 #[test]
 fn test_part1_input5() -> Result<()> {
-    let values = input_generator(TEST_INPUT_5)?;
+    let values = parse(TEST_INPUT_5)?;
     assert_eq!(12, part1(&values));
     Ok(())
 }
 
 #[test]
 fn test_part1_input6() -> Result<()> {
-    let values = input_generator(TEST_INPUT_6)?;
+    let values = parse(TEST_INPUT_6)?;
     assert_eq!(23, part1(&values));
     Ok(())
 }
 
 #[test]
 fn test_part1_input7() -> Result<()> {
-    let values = input_generator(TEST_INPUT_7)?;
+    let values = parse(TEST_INPUT_7)?;
     assert_eq!(31, part1(&values));
     Ok(())
 }
 
-#[test]
-fn test_part1_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day16.txt").trim())?;
-    assert_eq!(895, part1(&values));
-    Ok(())
-}
-
 #[test]
 fn test_part2_input1() -> Result<()> {
-    let values = input_generator("C200B40A82")?;
-    assert_eq!(3, part2(&values));
+    let values = parse("C200B40A82")?;
+    assert_eq!(3, part2(&values)?);
     Ok(())
 }
 
 #[test]
 fn test_part2_input2() -> Result<()> {
-    let values = input_generator("04005AC33890")?;
-    assert_eq!(54, part2(&values));
+    let values = parse("04005AC33890")?;
+    assert_eq!(54, part2(&values)?);
     Ok(())
 }
 
 #[test]
 fn test_part2_input3() -> Result<()> {
-    let values = input_generator("880086C3E88112")?;
-    assert_eq!(7, part2(&values));
+    let values = parse("880086C3E88112")?;
+    assert_eq!(7, part2(&values)?);
     Ok(())
 }
 
 #[test]
 fn test_part2_input4() -> Result<()> {
-    let values = input_generator("CE00C43D881120")?;
-    assert_eq!(9, part2(&values));
+    let values = parse("CE00C43D881120")?;
+    assert_eq!(9, part2(&values)?);
     Ok(())
 }
 
 #[test]
 fn test_part2_input5() -> Result<()> {
-    let values = input_generator("D8005AC2A8F0")?;
-    assert_eq!(1, part2(&values));
+    let values = parse("D8005AC2A8F0")?;
+    assert_eq!(1, part2(&values)?);
     Ok(())
 }
 
 #[test]
 fn test_part2_input6() -> Result<()> {
-    let values = input_generator("F600BC2D8F")?;
-    assert_eq!(0, part2(&values));
+    let values = parse("F600BC2D8F")?;
+    assert_eq!(0, part2(&values)?);
     Ok(())
 }
 
 #[test]
 fn test_part2_input7() -> Result<()> {
-    let values = input_generator("9C005AC2F8F0")?;
-    assert_eq!(0, part2(&values));
+    let values = parse("9C005AC2F8F0")?;
+    assert_eq!(0, part2(&values)?);
     Ok(())
 }
 
 #[test]
 fn test_part2_input8() -> Result<()> {
-    let values = input_generator("9C0141080250320F1802104A08")?;
-    assert_eq!(1, part2(&values));
+    let values = parse("9C0141080250320F1802104A08")?;
+    assert_eq!(1, part2(&values)?);
     Ok(())
 }
 
 #[test]
-fn test_part2_solution() -> Result<()> {
-    let values = input_generator(include_str!("../input/2021/day16.txt").trim())?;
-    assert_eq!(1148595959144, part2(&values));
-    Ok(())
+fn test_parse_error() {
+    let err = parse("D2FE2G").err();
+    assert_eq!(Some(ParseError::new(16, 1, 6, "a hexadecimal digit")), err);
+    // The literal packet ends after 21 bits, so the bits in the 6th hex character must be unset
+    let err = parse("D2FE28F0").err();
+    assert_eq!(
+        Some(ParseError::new(
+            16,
+            1,
+            7,
+            "only unset padding bits after the outermost packet"
+        )),
+        err
+    );
+    // The literal is cut off after two groups
+    let err = parse("D2FE").err();
+    assert_eq!(
+        Some(ParseError::new(16, 1, 5, "a group of literal bits")),
+        err
+    );
+    // The sum contains 6 bits of subpackets, which is only a header
+    let err = parse("00001840").err();
+    assert_eq!(
+        Some(ParseError::new(16, 1, 8, "a group of literal bits")),
+        err
+    );
+    // Greater than with a single subpacket
+    let err = parse("16004408").err();
+    assert_eq!(
+        Some(ParseError::new(
+            16,
+            1,
+            1,
+            "exactly two subpackets for a comparison"
+        )),
+        err
+    );
+    assert_eq!(
+        Some(ParseError::new(16, 1, 1, "a packet version")),
+        parse("").err()
+    );
+}
+
+#[test]
+fn test_part2_overflow() {
+    use BitsOperands::*;
+    use BitsOperatorType::*;
+    use BitsType::*;
+
+    let large = || BitsPacket {
+        version: 0,
+        ty: Lit { literal: 1 << 127 },
+    };
+    let packet = |ty| BitsPacket {
+        version: 0,
+        ty: Operator {
+            ty,
+            op: PacketDefined {
+                count: 2,
+                subpackets: vec![large(), large()],
+            },
+        },
+    };
+    assert!(part2(&packet(Sum)).is_err());
+    assert!(part2(&packet(Product)).is_err());
+    assert_eq!(1 << 127, part2(&packet(Maximum)).unwrap());
 }
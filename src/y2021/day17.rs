@@ -0,0 +1,292 @@
+//! # Day 17: Trick Shot
+
+#![allow(clippy::comparison_chain)]
+use crate::prelude::*;
+
+/// Area the probe needs to hit, with inclusive bounds
+#[derive(Clone, Copy, Debug)]
+pub struct TargetArea {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+}
+
+/// Parse the target area
+pub fn parse(input: &str) -> Result<TargetArea, ParseError> {
+    // target area: x=20..30, y=-10..-5
+    let (xvalues, yvalues) = input
+        .strip_prefix("target area: x=")
+        .ok_or_else(|| ParseError::at(17, input, input, "`target area: x=`"))?
+        .split_once(", y=")
+        .ok_or_else(|| ParseError::at(17, input, &input[input.len()..], "`, y=`"))?;
+    let parse_range = |range: &str| -> Result<(i32, i32), ParseError> {
+        let (start, end) = range
+            .split_once("..")
+            .ok_or_else(|| ParseError::at(17, input, &range[range.len()..], "`..`"))?;
+        let parse_number = |value: &str| {
+            value
+                .parse::<i32>()
+                .map_err(|_| ParseError::at(17, input, value, "a number"))
+        };
+        Ok((parse_number(start)?, parse_number(end)?))
+    };
+    let xvalues = parse_range(xvalues)?;
+    let yvalues = parse_range(yvalues)?;
+    Ok(TargetArea {
+        min_x: xvalues.0.min(xvalues.1),
+        min_y: yvalues.0.min(yvalues.1),
+        max_x: xvalues.0.max(xvalues.1),
+        max_y: yvalues.0.max(yvalues.1),
+    })
+}
+
+/// Highest y position reachable while still hitting the target area
+pub fn part1(input: &TargetArea) -> i32 {
+    let mut highest_y = 0;
+
+    for initial_x in 0..input.max_x {
+        for initial_y in 0..500 {
+            // Simulate shooting the probe from the initial 0,0 position with initial delta
+            let mut curr_x = 0;
+            let mut curr_y = 0;
+            let mut delta_x = initial_x;
+            let mut delta_y = initial_y;
+            let mut highest_y_of_shot = 0;
+
+            while curr_y >= input.min_y {
+                curr_x += delta_x;
+                curr_y += delta_y;
+                highest_y_of_shot = curr_y.max(highest_y_of_shot);
+                if delta_x > 0 {
+                    delta_x -= 1;
+                } else if delta_x < 0 {
+                    delta_x += 1;
+                }
+                delta_y -= 1;
+
+                if (input.min_x..=input.max_x).contains(&curr_x)
+                    && (input.min_y..=input.max_y).contains(&curr_y)
+                    && highest_y_of_shot > highest_y
+                {
+                    highest_y = highest_y_of_shot;
+                    log::debug!(
+                        "Found new highest y at {} for initial {},{}",
+                        highest_y,
+                        initial_x,
+                        initial_y
+                    );
+                }
+            }
+        }
+    }
+    highest_y
+}
+
+/// Number of initial velocities which hit the target area
+pub fn part2(input: &TargetArea) -> usize {
+    let mut initial_configurations = Set::new();
+
+    for initial_x in 0..input.max_x + 5 {
+        for initial_y in input.min_y..500 {
+            // Simulate shooting the probe from the initial 0,0 position with initial delta
+            let mut curr_x = 0;
+            let mut curr_y = 0;
+            let mut delta_x = initial_x;
+            let mut delta_y = initial_y;
+
+            while curr_y >= input.min_y {
+                curr_x += delta_x;
+                curr_y += delta_y;
+                if delta_x > 0 {
+                    delta_x -= 1;
+                } else if delta_x < 0 {
+                    delta_x += 1;
+                }
+                delta_y -= 1;
+
+                if (input.min_x..=input.max_x).contains(&curr_x)
+                    && (input.min_y..=input.max_y).contains(&curr_y)
+                {
+                    initial_configurations.insert((initial_x, initial_y));
+                }
+            }
+        }
+    }
+    log::trace!("Initial velocities: {:?}", initial_configurations);
+    initial_configurations.len()
+}
+
+/// Trajectory which reaches the highest position while still hitting the target area, like the diagrams in the puzzle description
+pub struct Shot {
+    target: TargetArea,
+    /// Positions of the probe after each step, ending in the target area
+    trajectory: Vec<(i32, i32)>,
+}
+
+impl Shot {
+    /// Shot of [`part1`], without a trajectory if no shot hits
+    pub fn highest(target: &TargetArea) -> Self {
+        let trajectory = (0..=target.max_x)
+            .flat_map(|initial_x| (target.min_y..500).map(move |initial_y| (initial_x, initial_y)))
+            .map(|velocity| trajectory(target, velocity))
+            .filter(
+                |trajectory| matches!(trajectory.last(), Some(&(x, y)) if target.contains(x, y)),
+            )
+            .max_by_key(|trajectory| trajectory.iter().map(|&(_, y)| y).max())
+            .unwrap_or_default();
+        Self {
+            target: *target,
+            trajectory,
+        }
+    }
+
+    /// `S` for the start, `#` for the probe, `T` for the target area and `.` for anything else
+    fn cells(&self) -> Result<Grid<char>> {
+        let points = || self.trajectory.iter().chain(&[(0, 0)]);
+        let min_x = points()
+            .map(|&(x, _)| x)
+            .min()
+            .unwrap()
+            .min(self.target.min_x);
+        let max_x = points()
+            .map(|&(x, _)| x)
+            .max()
+            .unwrap()
+            .max(self.target.max_x);
+        let min_y = points()
+            .map(|&(_, y)| y)
+            .min()
+            .unwrap()
+            .min(self.target.min_y);
+        let max_y = points()
+            .map(|&(_, y)| y)
+            .max()
+            .unwrap()
+            .max(self.target.max_y);
+        // The y axis points up, so the top row is the highest position
+        let cell = |x: i32, y: i32| ((x - min_x) as usize, (max_y - y) as usize);
+        let (width, height) = (
+            (max_x as i64 - min_x as i64 + 1) as usize,
+            (max_y as i64 - min_y as i64 + 1) as usize,
+        );
+        let mut cells = Grid::try_new(width, height, '.').ok_or_else(|| {
+            anyhow!(
+                "The shot of {}x{} cells is too large to draw",
+                width,
+                height
+            )
+        })?;
+        for x in self.target.min_x..=self.target.max_x {
+            for y in self.target.min_y..=self.target.max_y {
+                cells[cell(x, y)] = 'T';
+            }
+        }
+        for &(x, y) in &self.trajectory {
+            cells[cell(x, y)] = '#';
+        }
+        cells[cell(0, 0)] = 'S';
+        Ok(cells)
+    }
+}
+
+impl TargetArea {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+}
+
+/// Positions of the probe after each step, until it is in the target area or cannot reach it anymore
+fn trajectory(target: &TargetArea, (mut delta_x, mut delta_y): (i32, i32)) -> Vec<(i32, i32)> {
+    let mut trajectory = Vec::new();
+    let (mut curr_x, mut curr_y) = (0, 0);
+    while curr_y >= target.min_y && curr_x <= target.max_x && !target.contains(curr_x, curr_y) {
+        curr_x += delta_x;
+        curr_y += delta_y;
+        delta_x -= delta_x.signum();
+        delta_y -= 1;
+        trajectory.push((curr_x, curr_y));
+    }
+    trajectory
+}
+
+impl Render for Shot {
+    fn ascii(&self) -> Result<String> {
+        Ok(self.cells()?.to_string())
+    }
+
+    /// The start in blue, the probe in red and the target area in green
+    fn image(&self) -> Result<Image> {
+        Ok(Image::Color(self.cells()?.map(|&cell| match cell {
+            'S' => [0, 0, 255],
+            '#' => [255, 0, 0],
+            'T' => [0, 160, 0],
+            _ => [255, 255, 255],
+        })))
+    }
+}
+
+/// Day 17: Trick Shot
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 17;
+    type Input = TargetArea;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(part2(input))
+    }
+
+    fn render(input: &Self::Input) -> Option<Box<dyn Render>> {
+        Some(Box::new(Shot::highest(input)))
+    }
+}
+
+#[cfg(test)]
+static TEST_INPUT_1: &str = r"target area: x=20..30, y=-10..-5";
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(45, part1(&values));
+    Ok(())
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(112, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_shot() -> Result<()> {
+    let shot = Shot::highest(&parse(TEST_INPUT_1)?);
+    assert_eq!(Some(45), shot.trajectory.iter().map(|&(_, y)| y).max());
+    let ascii = shot.ascii()?;
+    // From the highest position at 45 down to the bottom of the target area at -10
+    assert_eq!(56, ascii.lines().count());
+    assert!(ascii.lines().next().unwrap().contains('#'));
+    assert_eq!(Some('S'), ascii.lines().nth(45).unwrap().chars().next());
+    assert_eq!(11 * 6, ascii.matches('T').count() + 1);
+    Ok(())
+}
+
+#[test]
+fn test_parse_error() {
+    let err = parse("target area: x=20..30, y=-10..-x").err();
+    assert_eq!(Some(ParseError::new(17, 1, 31, "a number")), err);
+    let err = parse("target area: x=20..30").err();
+    assert_eq!(Some(ParseError::new(17, 1, 22, "`, y=`")), err);
+}
@@ -0,0 +1,537 @@
+//! # Day 18: Snailfish
+
+use crate::prelude::*;
+#[cfg(test)]
+use pretty_assertions::assert_eq;
+
+/// Parse the snailfish numbers, one per line, which must be reduced
+pub fn parse(input: &str) -> Result<Vec<SnailfishNumberList>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let mut elements = Vec::new();
+            // Number of elements in each pair which is still open, the outermost first
+            let mut open_pairs: Vec<u8> = Vec::new();
+            for (idx, c) in line.char_indices() {
+                let error = |expected| ParseError::at(18, input, &line[idx..], expected);
+                let outside_pair = if elements.is_empty() {
+                    "`[`"
+                } else {
+                    "the end of the line"
+                };
+                let element = match c {
+                    '[' => SnailfishElement::Open,
+                    ']' => SnailfishElement::Close,
+                    '0'..='9' => SnailfishElement::Number(c as u32 - '0' as u32),
+                    ',' => continue,
+                    _ => return Err(error("`[`, `]`, `,` or a digit")),
+                };
+
+                // Ensure every number is a single pair, such that it can be added and reduced
+                if element == SnailfishElement::Close {
+                    match open_pairs.pop() {
+                        Some(2) => {}
+                        Some(_) => return Err(error("a pair or a digit")),
+                        None => return Err(error(outside_pair)),
+                    }
+                } else {
+                    match open_pairs.last_mut() {
+                        Some(count) if *count < 2 => *count += 1,
+                        Some(_) => return Err(error("`]`")),
+                        None if elements.is_empty() && element == SnailfishElement::Open => {}
+                        None => return Err(error(outside_pair)),
+                    }
+                    if element == SnailfishElement::Open {
+                        // Only reduced numbers can be added
+                        if open_pairs.len() == 4 {
+                            return Err(error(
+                                "a digit, as pairs cannot be nested inside four pairs",
+                            ));
+                        }
+                        open_pairs.push(0);
+                    }
+                }
+                elements.push(element);
+            }
+
+            let expected = match open_pairs.last() {
+                Some(2) => "`]`",
+                Some(_) => "a pair or a digit",
+                None if elements.is_empty() => "`[`",
+                None => return Ok(SnailfishNumberList(elements)),
+            };
+            Err(ParseError::at(18, input, &line[line.len()..], expected))
+        })
+        .collect()
+}
+
+/// Magnitude of the sum of all numbers
+pub fn part1(input: &[SnailfishNumberList]) -> Result<u32> {
+    let sum = add_numbers(input.to_vec()).ok_or_else(|| anyhow!("No numbers to add"))?;
+    Ok(sum.magnitude())
+}
+
+/// A snailfish number, stored as the sequence of its brackets and regular numbers
+///
+/// Adding two numbers reduces their sum.
+#[derive(PartialEq, Eq, Clone)]
+pub struct SnailfishNumberList(Vec<SnailfishElement>);
+
+impl SnailfishNumberList {
+    /// Three times the magnitude of the left element plus two times the magnitude of the right element
+    pub fn magnitude(&self) -> u32 {
+        // Turn self into a tree
+        enum Tree {
+            Leaf(u32),
+            Pair(Box<Tree>, Box<Tree>),
+        }
+
+        impl Tree {
+            fn magnitude(&self) -> u32 {
+                match self {
+                    Tree::Leaf(n) => *n,
+                    Tree::Pair(left, right) => left.magnitude() * 3 + right.magnitude() * 2,
+                }
+            }
+        }
+
+        fn parse_tree(i: &mut impl Iterator<Item = SnailfishElement>) -> Tree {
+            match i.next() {
+                Some(SnailfishElement::Number(n)) => Tree::Leaf(n),
+                Some(SnailfishElement::Open) => {
+                    let left = parse_tree(i);
+                    let right = parse_tree(i);
+                    // Consume close
+                    i.next();
+                    Tree::Pair(Box::new(left), Box::new(right))
+                }
+                // `parse` ensures every number is a complete pair
+                Some(SnailfishElement::Close) | None => unreachable!("Incomplete pair"),
+            }
+        }
+
+        parse_tree(&mut self.0.iter().cloned()).magnitude()
+    }
+}
+
+impl std::fmt::Debug for SnailfishNumberList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut last_was_number = false;
+        for e in &self.0 {
+            if matches!(e, SnailfishElement::Number(_)) {
+                if last_was_number {
+                    write!(f, ",")?;
+                }
+                last_was_number = true;
+            } else {
+                last_was_number = false;
+            }
+
+            write!(f, "{:?}", e)?;
+        }
+        Ok(())
+    }
+}
+
+/// Notation of the puzzle description, with a comma between the two elements of each pair
+impl std::fmt::Display for SnailfishNumberList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut after_element = false;
+        for e in &self.0 {
+            if after_element && *e != SnailfishElement::Close {
+                write!(f, ",")?;
+            }
+            after_element = *e != SnailfishElement::Open;
+            write!(f, "{:?}", e)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum SnailfishElement {
+    Open,
+    Close,
+    Number(u32),
+}
+
+impl std::fmt::Debug for SnailfishElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "["),
+            Self::Close => write!(f, "]"),
+            Self::Number(num) => write!(f, "{}", num),
+        }
+    }
+}
+
+impl std::ops::Add for SnailfishNumberList {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut result = Vec::with_capacity(self.0.len() + rhs.0.len() + 2);
+        result.push(SnailfishElement::Open);
+        result.extend(self.0);
+        result.extend(rhs.0);
+        result.push(SnailfishElement::Close);
+
+        reduce(Self(result))
+    }
+}
+
+/// Sum of all numbers, or `None` if there are none
+fn add_numbers(numbers: Vec<SnailfishNumberList>) -> Option<SnailfishNumberList> {
+    numbers.into_iter().reduce(|l, r| l + r)
+}
+
+fn reduce(mut sfn: SnailfishNumberList) -> SnailfishNumberList {
+    loop {
+        // Always restart the loop if one of the options works
+        if let Some(new_sfn) = explode(&sfn) {
+            sfn = new_sfn;
+            continue;
+        }
+        if let Some(new_sfn) = split(&sfn) {
+            sfn = new_sfn;
+            continue;
+        }
+
+        // No more options, so we're done
+        return sfn;
+    }
+}
+
+fn explode(sfn: &SnailfishNumberList) -> Option<SnailfishNumberList> {
+    // Find the first place with a nesting level of 4
+    let mut nesting_level = 0;
+    let mut nesting_idx = None;
+
+    for (idx, e) in sfn.0.iter().enumerate() {
+        match e {
+            SnailfishElement::Open => {
+                nesting_level += 1;
+            }
+            SnailfishElement::Close => {
+                nesting_level -= 1;
+            }
+            SnailfishElement::Number(_) => {}
+        }
+        if nesting_level == 5 {
+            nesting_idx = Some(idx);
+            break;
+        }
+    }
+
+    // No number if nested too deeply
+    let nesting_idx = nesting_idx?;
+    match sfn.0[nesting_idx..nesting_idx + 4] {
+        [SnailfishElement::Open, SnailfishElement::Number(left), SnailfishElement::Number(right), SnailfishElement::Close] =>
+        {
+            let mut result = Vec::with_capacity(sfn.0.len() - 3);
+            result.extend_from_slice(&sfn.0[..nesting_idx]);
+            // Find the previous number and add the left value
+            if let Some(SnailfishElement::Number(num)) = result
+                .iter_mut()
+                .rev()
+                .find(|e| matches!(e, SnailfishElement::Number(_)))
+            {
+                *num += left;
+            }
+            // The exploded element turns into a number
+            result.push(SnailfishElement::Number(0));
+            result.extend_from_slice(&sfn.0[nesting_idx + 4..]);
+            // Find the next number and add the left value
+            if let Some(SnailfishElement::Number(num)) = (&mut result[nesting_idx + 1..])
+                .iter_mut()
+                .find(|e| matches!(e, SnailfishElement::Number(_)))
+            {
+                *num += right;
+            }
+
+            Some(SnailfishNumberList(result))
+        }
+        // `parse` only accepts reduced numbers, so adding two of them nests only pairs of numbers this deep
+        _ => unreachable!("Pair nested too deeply"),
+    }
+}
+
+fn split(sfn: &SnailfishNumberList) -> Option<SnailfishNumberList> {
+    // TODO only split the first number
+    if let Some((idx, SnailfishElement::Number(num))) = sfn
+        .0
+        .iter()
+        .enumerate()
+        .find(|&(_, &e)| matches!(e, SnailfishElement::Number(num) if num >= 10))
+    {
+        let mut result = Vec::with_capacity(sfn.0.len() + 3);
+        result.extend_from_slice(&sfn.0[..idx]);
+
+        // First, divide by 2 rounded down
+        // Second, divide by 2 rounded up
+        result.push(SnailfishElement::Open);
+        result.push(SnailfishElement::Number(num / 2));
+        result.push(SnailfishElement::Number((num + 1) / 2));
+        result.push(SnailfishElement::Close);
+
+        result.extend_from_slice(&sfn.0[idx + 1..]);
+
+        Some(SnailfishNumberList(result))
+    } else {
+        None
+    }
+}
+
+/// Largest magnitude of the sum of any two different numbers
+pub fn part2(input: &[SnailfishNumberList]) -> u32 {
+    let mut max_magnitude = 0;
+    for (idx_a, sfn_a) in input.iter().enumerate() {
+        for (idx_b, sfn_b) in input.iter().enumerate() {
+            if idx_a != idx_b {
+                max_magnitude = max_magnitude.max((sfn_a.clone() + sfn_b.clone()).magnitude());
+            }
+        }
+    }
+    max_magnitude
+}
+
+/// Partial sums of the homework, one per line, like the step by step example of the puzzle description
+pub struct Homework(pub Vec<SnailfishNumberList>);
+
+impl Homework {
+    pub fn new(input: &[SnailfishNumberList]) -> Self {
+        let mut sums: Vec<SnailfishNumberList> = Vec::with_capacity(input.len());
+        for number in input {
+            let sum = match sums.last() {
+                Some(sum) => sum.clone() + number.clone(),
+                None => number.clone(),
+            };
+            sums.push(sum);
+        }
+        Self(sums)
+    }
+}
+
+impl Render for Homework {
+    fn ascii(&self) -> Result<String> {
+        Ok(self.0.iter().map(|sum| format!("{}\n", sum)).collect())
+    }
+
+    /// One row per sum and one pixel per regular number, darker for larger numbers
+    fn image(&self) -> Result<Image> {
+        let regulars = |sum: &SnailfishNumberList| -> Vec<u32> {
+            sum.0
+                .iter()
+                .filter_map(|e| match e {
+                    SnailfishElement::Number(n) => Some(*n),
+                    _ => None,
+                })
+                .collect()
+        };
+        let rows = self.0.iter().map(regulars).collect::<Vec<_>>();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut grid = Grid::new(width, rows.len(), 255);
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &n) in row.iter().enumerate() {
+                // Reduced numbers have no regular number above 9
+                grid[(x, y)] = crate::render::shade(n, 9);
+            }
+        }
+        Ok(Image::Gray(grid))
+    }
+}
+
+/// Day 18: Snailfish
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 18;
+    type Input = Vec<SnailfishNumberList>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u32> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u32> {
+        Ok(part2(input))
+    }
+
+    fn render(input: &Self::Input) -> Option<Box<dyn Render>> {
+        Some(Box::new(Homework::new(input)))
+    }
+}
+
+#[cfg(test)]
+static TEST_INPUT_1: &str = r"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+#[test]
+fn test_step_by_step() -> Result<()> {
+    let input = r#"[[[[4,3],4],4],[7,[[8,4],9]]]
+[1,1]"#;
+    let values = parse(input)?;
+    let expected = &parse("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")?[0];
+
+    let mut sfn = {
+        let mut result = Vec::with_capacity(values[0].0.len() + values[1].0.len() + 2);
+        result.push(SnailfishElement::Open);
+        result.extend(&values[0].0);
+        result.extend(&values[1].0);
+        result.push(SnailfishElement::Close);
+        SnailfishNumberList(result)
+    };
+
+    dbg!(&sfn);
+    sfn = explode(&sfn).unwrap();
+    dbg!(&sfn);
+    sfn = explode(&sfn).unwrap();
+    dbg!(&sfn);
+    sfn = split(&sfn).unwrap();
+    dbg!(&sfn);
+    sfn = split(&sfn).unwrap();
+    dbg!(&sfn);
+    sfn = explode(&sfn).unwrap();
+    dbg!(&sfn);
+
+    assert_eq!(expected, &sfn);
+    Ok(())
+}
+
+#[test]
+fn test_homework() -> Result<()> {
+    let homework = Homework::new(&parse("[1,1]\n[2,2]\n[3,3]")?);
+    assert_eq!(
+        "[1,1]\n[[1,1],[2,2]]\n[[[1,1],[2,2]],[3,3]]\n",
+        homework.ascii()?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_add_a() -> Result<()> {
+    let input = r#"[1,1]
+[2,2]
+[3,3]
+[4,4]"#;
+    let values = parse(input)?;
+    let expected = &parse("[[[[1,1],[2,2]],[3,3]],[4,4]]")?[0];
+
+    assert_eq!(Some(expected), add_numbers(values).as_ref());
+    Ok(())
+}
+
+#[test]
+fn test_add_b() -> Result<()> {
+    let input = r#"[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]"#;
+    let values = parse(input)?;
+    let expected = &parse("[[[[3,0],[5,3]],[4,4]],[5,5]]")?[0];
+
+    assert_eq!(Some(expected), add_numbers(values).as_ref());
+    Ok(())
+}
+
+#[test]
+fn test_add_c() -> Result<()> {
+    let input = r#"[1,1]
+[2,2]
+[3,3]
+[4,4]
+[5,5]
+[6,6]"#;
+    let values = parse(input)?;
+    let expected = &parse("[[[[5,0],[7,4]],[5,5]],[6,6]]")?[0];
+
+    assert_eq!(Some(expected), add_numbers(values).as_ref());
+    Ok(())
+}
+
+#[test]
+fn test_add_d() -> Result<()> {
+    let input = r#"[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+[7,[5,[[3,8],[1,4]]]]
+[[2,[2,2]],[8,[8,1]]]
+[2,9]
+[1,[[[9,3],9],[[9,0],[0,7]]]]
+[[[5,[7,4]],7],1]
+[[[[4,2],2],6],[8,7]]"#;
+    let values = parse(input)?;
+    let expected = &parse("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")?[0];
+
+    assert_eq!(Some(expected), add_numbers(values).as_ref());
+    Ok(())
+}
+
+#[test]
+fn test_part1() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(4140, part1(&values)?);
+    Ok(())
+}
+
+#[test]
+fn test_part1_empty() {
+    assert!(part1(&[]).is_err());
+}
+
+#[test]
+fn test_part2() -> Result<()> {
+    let values = parse(TEST_INPUT_1)?;
+    assert_eq!(3993, part2(&values));
+    Ok(())
+}
+
+#[test]
+fn test_parse_error() {
+    let err = parse("[1,2]\n[[1,2],x]").err();
+    assert_eq!(
+        Some(ParseError::new(18, 2, 8, "`[`, `]`, `,` or a digit")),
+        err
+    );
+    assert_eq!(Some(ParseError::new(18, 1, 1, "`[`")), parse("1").err());
+    assert_eq!(
+        Some(ParseError::new(18, 1, 6, "the end of the line")),
+        parse("[1,2]]").err()
+    );
+    assert_eq!(
+        Some(ParseError::new(18, 1, 6, "`]`")),
+        parse("[1,2,3]").err()
+    );
+    assert_eq!(
+        Some(ParseError::new(18, 1, 3, "a pair or a digit")),
+        parse("[1]").err()
+    );
+    assert_eq!(
+        Some(ParseError::new(18, 1, 13, "`]`")),
+        parse("[[1,2],[3,4]").err()
+    );
+    assert_eq!(
+        Some(ParseError::new(
+            18,
+            1,
+            5,
+            "a digit, as pairs cannot be nested inside four pairs"
+        )),
+        parse("[[[[[1,2],3],4],5],6]").err()
+    );
+}
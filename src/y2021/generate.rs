@@ -0,0 +1,790 @@
+//! Seeded generators for synthetic puzzle inputs
+//!
+//! The generated inputs can be far larger than the official ones, which is useful to measure how the solutions scale and to feed fuzzers.
+//! The same seed and size always produce the same input.
+
+use crate::prelude::*;
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::cmp::Reverse;
+
+/// A synthetic puzzle input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    /// Expected answer for part 1, if it is cheap to compute while generating the input
+    pub part1: Option<String>,
+    /// Expected answer for part 2, if it is cheap to compute while generating the input
+    pub part2: Option<String>,
+}
+
+/// ChaCha produces the same values on every platform and for every version of rand
+fn rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// Sonar sweep with `measurements` depths, which must be at least 3
+pub fn day01(seed: u64, measurements: usize) -> Generated {
+    assert!(measurements >= 3, "Day 1 needs at least 3 measurements");
+    let mut rng = rng(seed);
+
+    let mut depth: u32 = rng.gen_range(100..1000);
+    let mut depths = Vec::with_capacity(measurements);
+    for _ in 0..measurements {
+        // Mostly increasing, like the sea floor in the puzzle
+        depth = (depth + rng.gen_range(0..20)).saturating_sub(rng.gen_range(0..15));
+        depth = depth.max(1);
+        depths.push(depth);
+    }
+
+    let increases = depths.windows(2).filter(|w| w[1] > w[0]).count();
+    // Consecutive windows share two values, so only the differing ones need to be compared
+    let window_increases = depths.windows(4).filter(|w| w[3] > w[0]).count();
+    Generated {
+        input: depths.iter().join("\n"),
+        part1: Some(increases.to_string()),
+        part2: Some(window_increases.to_string()),
+    }
+}
+
+/// Planned course with `commands` commands, at most 2000 such that both answers fit into 32 bits
+///
+/// `up` never takes the depth or the aim above the surface, which both parts need.
+pub fn day02(seed: u64, commands: usize) -> Generated {
+    assert!(commands <= 2000, "Day 2 takes at most 2000 commands");
+    let mut rng = rng(seed);
+
+    let (mut horizontal, mut aim, mut depth) = (0, 0, 0);
+    let input = (0..commands)
+        .map(|_| {
+            // Stay shallow, as the depth of part 2 grows with the aim
+            let (direction, distance) = match rng.gen_range(0..3) {
+                0 if aim < 10 => ("down", rng.gen_range(1..=10 - aim)),
+                1 if aim > 0 => ("up", rng.gen_range(1..=aim)),
+                _ => ("forward", rng.gen_range(1..10)),
+            };
+            match direction {
+                "down" => aim += distance,
+                "up" => aim -= distance,
+                _ => {
+                    horizontal += distance;
+                    depth += aim * distance;
+                }
+            }
+            format!("{} {}", direction, distance)
+        })
+        .join("\n");
+    Generated {
+        input,
+        // Without the aim, `up` and `down` change the depth just like the aim
+        part1: Some((horizontal * aim).to_string()),
+        part2: Some((horizontal * depth).to_string()),
+    }
+}
+
+/// Diagnostic report with `numbers` different numbers of `bits` bits, at most 16 such that both answers fit into 32 bits
+///
+/// Ties count as 1 for the most common bit.
+pub fn day03(seed: u64, numbers: usize, bits: u32) -> Generated {
+    assert!((1..=16).contains(&bits), "Day 3 needs 1 to 16 bits");
+    assert!(
+        (1..=1 << bits).contains(&numbers),
+        "Day 3 needs between 1 and 2^bits numbers"
+    );
+    let mut rng = rng(seed);
+
+    let mut values: Vec<u32> = index::sample(&mut rng, 1 << bits, numbers)
+        .into_iter()
+        .map(|value| value as u32)
+        .collect();
+    let bit = |value: u32, pos: u32| value >> (bits - 1 - pos) & 1 == 1;
+    let most_common = |values: &[&u32], pos: u32| {
+        2 * values.iter().filter(|&&&value| bit(value, pos)).count() >= values.len()
+    };
+
+    // The CO2 rating keeps the numbers with the least common bit, which must not be none of them.
+    // Flipping a bit moves a number into the empty half without changing the counts above it.
+    let mut prefix = Vec::new();
+    for pos in 0..bits {
+        let candidates = (0..values.len())
+            .filter(|&idx| (0..pos).all(|p| bit(values[idx], p) == prefix[p as usize]))
+            .collect_vec();
+        if candidates.len() < 2 {
+            break;
+        }
+        let ones = candidates
+            .iter()
+            .filter(|&&idx| bit(values[idx], pos))
+            .count();
+        if ones == 0 || ones == candidates.len() {
+            values[*candidates.choose(&mut rng).unwrap()] ^= 1 << (bits - 1 - pos);
+        }
+        let refs = candidates.iter().map(|&idx| &values[idx]).collect_vec();
+        prefix.push(!most_common(&refs, pos));
+    }
+
+    let rating = |keep_most_common: bool| {
+        let mut remaining = values.iter().collect_vec();
+        for pos in 0..bits {
+            if remaining.len() == 1 {
+                break;
+            }
+            let keep = most_common(&remaining, pos) == keep_most_common;
+            remaining.retain(|&&value| bit(value, pos) == keep);
+        }
+        *remaining[0]
+    };
+    let gamma = (0..bits).fold(0, |acc, pos| {
+        (acc << 1) | most_common(&values.iter().collect_vec(), pos) as u32
+    });
+    let epsilon = !gamma & ((1 << bits) - 1);
+    Generated {
+        input: values
+            .iter()
+            .map(|value| format!("{:0width$b}", value, width = bits as usize))
+            .join("\n"),
+        part1: Some((gamma * epsilon).to_string()),
+        part2: Some((rating(true) * rating(false)).to_string()),
+    }
+}
+
+/// Bingo with `boards` boards, which must be at least 1
+///
+/// All numbers from 0 to 99 are drawn, such that every board wins eventually.
+pub fn day04(seed: u64, boards: usize) -> Generated {
+    assert!(boards >= 1, "Day 4 needs at least 1 board");
+    let mut rng = rng(seed);
+
+    let mut numbers: Vec<usize> = (0..100).collect();
+    numbers.shuffle(&mut rng);
+    let mut drawn_at = [0; 100];
+    for (turn, &number) in numbers.iter().enumerate() {
+        drawn_at[number] = turn;
+    }
+
+    let mut input = numbers.iter().join(",");
+    // Turn and score at which each board wins
+    let mut wins = Vec::with_capacity(boards);
+    for _ in 0..boards {
+        let fields = index::sample(&mut rng, 100, 25).into_vec();
+        let rows = fields.chunks(5).collect_vec();
+
+        let turn = (0..5)
+            .flat_map(|i| {
+                let row = rows[i].iter().map(|&n| drawn_at[n]).max();
+                let column = rows.iter().map(|row| drawn_at[row[i]]).max();
+                [row, column]
+            })
+            .flatten()
+            .min()
+            .unwrap();
+        let unmarked: usize = fields.iter().filter(|&&n| drawn_at[n] > turn).sum();
+        wins.push((turn, unmarked * numbers[turn]));
+
+        input.push_str("\n\n");
+        input.push_str(
+            &rows
+                .iter()
+                .map(|row| row.iter().map(|n| format!("{:>2}", n)).join(" "))
+                .join("\n"),
+        );
+    }
+
+    // If several boards win at the same time, the one listed first counts
+    let (_, first_score) = wins.iter().min_by_key(|&&(turn, _)| turn).unwrap();
+    let (_, (_, last_score)) = wins
+        .iter()
+        .enumerate()
+        .max_by_key(|&(idx, &(turn, _))| (turn, Reverse(idx)))
+        .unwrap();
+    Generated {
+        input,
+        part1: Some(first_score.to_string()),
+        part2: Some(last_score.to_string()),
+    }
+}
+
+/// `lines` horizontal, vertical and diagonal lines of vents with coordinates below `size`
+pub fn day05(seed: u64, lines: usize, size: u32) -> Generated {
+    assert!(size >= 1, "Day 5 needs a non-empty area");
+    let mut rng = rng(seed);
+
+    let input = (0..lines)
+        .map(|_| {
+            let (x1, y1) = (rng.gen_range(0..size), rng.gen_range(0..size));
+            let (x2, y2) = match rng.gen_range(0..3) {
+                0 => (rng.gen_range(0..size), y1),
+                1 => (x1, rng.gen_range(0..size)),
+                _ => {
+                    // Pick a direction and a length which stays inside the area
+                    let (right, down) = (rng.gen_bool(0.5), rng.gen_bool(0.5));
+                    let max_x = if right { size - 1 - x1 } else { x1 };
+                    let max_y = if down { size - 1 - y1 } else { y1 };
+                    let len = rng.gen_range(0..=max_x.min(max_y));
+                    (
+                        if right { x1 + len } else { x1 - len },
+                        if down { y1 + len } else { y1 - len },
+                    )
+                }
+            };
+            format!("{},{} -> {},{}", x1, y1, x2, y2)
+        })
+        .join("\n");
+    Generated {
+        input,
+        part1: None,
+        part2: None,
+    }
+}
+
+/// School of `fish` lanternfish, which must be at least 1, with timers between 1 and 5 like in the puzzle
+pub fn day06(seed: u64, fish: usize) -> Generated {
+    assert!(fish >= 1, "Day 6 needs at least 1 fish");
+    let mut rng = rng(seed);
+
+    let timers = (0..fish).map(|_| rng.gen_range(1..=5)).collect_vec();
+    let count_after = |days: usize| {
+        let mut counts = [0_u64; 9];
+        for &timer in &timers {
+            counts[timer] += 1;
+        }
+        for _ in 0..days {
+            counts.rotate_left(1);
+            // The parents start over at 6, next to their children at 8
+            counts[6] += counts[8];
+        }
+        counts.iter().sum::<u64>()
+    };
+    Generated {
+        input: timers.iter().join(","),
+        part1: Some(count_after(80).to_string()),
+        part2: Some(count_after(256).to_string()),
+    }
+}
+
+/// `crabs` crabs, which must be at least 1, at positions below `size`
+///
+/// The answers come from trying every position, so this takes time proportional to `crabs * size`.
+pub fn day07(seed: u64, crabs: usize, size: u64) -> Generated {
+    assert!(
+        crabs >= 1 && size >= 1,
+        "Day 7 needs at least 1 crab and 1 position"
+    );
+    let mut rng = rng(seed);
+
+    let positions = (0..crabs).map(|_| rng.gen_range(0..size)).collect_vec();
+    let cheapest = |cost: fn(u64) -> u64| {
+        (0..size)
+            .map(|target| {
+                positions
+                    .iter()
+                    .map(|&pos| cost(pos.abs_diff(target)))
+                    .sum::<u64>()
+            })
+            .min()
+            .unwrap()
+    };
+    Generated {
+        input: positions.iter().join(","),
+        part1: Some(cheapest(|diff| diff).to_string()),
+        part2: Some(cheapest(|diff| diff * (diff + 1) / 2).to_string()),
+    }
+}
+
+/// Segments of each digit on a seven-segment display, as indices of `a` to `g`
+const SEGMENTS: [&[usize]; 10] = [
+    &[0, 1, 2, 4, 5, 6],
+    &[2, 5],
+    &[0, 2, 3, 4, 6],
+    &[0, 2, 3, 5, 6],
+    &[1, 2, 3, 5],
+    &[0, 1, 3, 5, 6],
+    &[0, 1, 3, 4, 5, 6],
+    &[0, 2, 5],
+    &[0, 1, 2, 3, 4, 5, 6],
+    &[0, 1, 2, 3, 5, 6],
+];
+
+/// Notes with `entries` entries, each wired up differently
+pub fn day08(seed: u64, entries: usize) -> Generated {
+    let mut rng = rng(seed);
+
+    let mut easy_digits = 0;
+    let mut sum = 0;
+    let input = (0..entries)
+        .map(|_| {
+            let mut wires: Vec<char> = ('a'..='g').collect();
+            wires.shuffle(&mut rng);
+            let mut digits: Vec<usize> = (0..10).collect();
+            digits.shuffle(&mut rng);
+            let samples = digits
+                .iter()
+                .map(|&digit| pattern(&mut rng, &wires, digit))
+                .join(" ");
+
+            let output = (0..4).map(|_| rng.gen_range(0..10)).collect_vec();
+            easy_digits += output
+                .iter()
+                .filter(|&&digit| matches!(digit, 1 | 4 | 7 | 8))
+                .count();
+            sum += output.iter().fold(0, |acc, &digit| acc * 10 + digit);
+            format!(
+                "{} | {}",
+                samples,
+                output
+                    .iter()
+                    .map(|&digit| pattern(&mut rng, &wires, digit))
+                    .join(" ")
+            )
+        })
+        .join("\n");
+    Generated {
+        input,
+        part1: Some(easy_digits.to_string()),
+        part2: Some(sum.to_string()),
+    }
+}
+
+/// Lit segments of `digit` in random order, with the segments wired up to `wires`
+fn pattern(rng: &mut ChaCha8Rng, wires: &[char], digit: usize) -> String {
+    let mut letters = SEGMENTS[digit].iter().map(|&s| wires[s]).collect_vec();
+    letters.shuffle(rng);
+    letters.into_iter().collect()
+}
+
+/// Opening and closing characters of chunks, in the order of their completion scores
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Navigation subsystem with `lines` corrupted or incomplete lines, of which an odd number is incomplete
+pub fn day10(seed: u64, lines: usize) -> Generated {
+    let mut rng = rng(seed);
+
+    let incomplete_lines = match rng.gen_range(0..=lines) {
+        0 if lines > 0 => 1,
+        count if count % 2 == 0 => count - 1,
+        count => count,
+    };
+    let mut error_score = 0;
+    let mut completion_scores = Vec::new();
+    let mut input = (0..lines)
+        .map(|idx| {
+            let mut line = Vec::new();
+            chunks(&mut rng, 0, &mut line);
+            if idx < incomplete_lines {
+                // Cut the line where chunks are still open
+                let mut open = Vec::new();
+                let mut cuts = Vec::new();
+                for (pos, &c) in line.iter().enumerate() {
+                    match PAIRS.iter().position(|&(opening, _)| opening == c) {
+                        Some(pair) => open.push(pair),
+                        None => {
+                            open.pop();
+                        }
+                    }
+                    if !open.is_empty() {
+                        cuts.push((pos + 1, open.clone()));
+                    }
+                }
+                let (cut, open) = cuts.choose(&mut rng).unwrap();
+                line.truncate(*cut);
+                completion_scores.push(
+                    open.iter()
+                        .rev()
+                        .fold(0_u64, |score, &pair| score * 5 + pair as u64 + 1),
+                );
+            } else {
+                // Close a chunk with the wrong character
+                let closings = (0..line.len())
+                    .filter(|&pos| PAIRS.iter().any(|&(_, closing)| closing == line[pos]))
+                    .collect_vec();
+                let pos = *closings.choose(&mut rng).unwrap();
+                let &&(_, wrong) = PAIRS
+                    .iter()
+                    .filter(|&&(_, closing)| closing != line[pos])
+                    .collect_vec()
+                    .choose(&mut rng)
+                    .unwrap();
+                line[pos] = wrong;
+                error_score += match wrong {
+                    ')' => 3,
+                    ']' => 57,
+                    '}' => 1197,
+                    _ => 25137,
+                };
+            }
+            line.into_iter().collect::<String>()
+        })
+        .collect_vec();
+    input.shuffle(&mut rng);
+
+    completion_scores.sort_unstable();
+    Generated {
+        input: input.join("\n"),
+        part1: Some(error_score.to_string()),
+        part2: completion_scores
+            .get(completion_scores.len() / 2)
+            .map(|score| score.to_string()),
+    }
+}
+
+/// Append one or more chunks, which nest at most 20 deep such that completion scores fit into 64 bits
+fn chunks(rng: &mut ChaCha8Rng, depth: usize, out: &mut Vec<char>) {
+    loop {
+        let (opening, closing) = *PAIRS.choose(rng).unwrap();
+        out.push(opening);
+        if depth < 20 && rng.gen_bool(0.6) {
+            chunks(rng, depth + 1, out);
+        }
+        out.push(closing);
+        if rng.gen_bool(0.5) {
+            return;
+        }
+    }
+}
+
+/// Connected cave system with `caves` caves besides `start` and `end`
+///
+/// Two big caves are never connected, as there would be infinitely many paths otherwise.
+pub fn day12(seed: u64, caves: usize) -> Generated {
+    let mut rng = rng(seed);
+
+    let mut names = vec!["start".to_string(), "end".to_string()];
+    let mut is_big = vec![false, false];
+    for idx in 0..caves {
+        let big = rng.gen_bool(0.25);
+        // The `c` prefix avoids clashes with `start` and `end`
+        let mut name = format!("c{}", cave_letters(idx));
+        if big {
+            name.make_ascii_uppercase();
+        }
+        names.push(name);
+        is_big.push(big);
+    }
+
+    let mut connections = Set::new();
+    // Connect every cave to an earlier one first, such that all caves are reachable from `start`
+    for cave in 1..names.len() {
+        let candidates = (0..cave)
+            .filter(|&other| !(is_big[cave] && is_big[other]))
+            .collect_vec();
+        let other = *candidates.choose(&mut rng).unwrap();
+        connections.insert((other, cave));
+    }
+    for _ in 0..caves / 2 {
+        let (a, b) = (rng.gen_range(0..names.len()), rng.gen_range(0..names.len()));
+        if a != b && !(is_big[a] && is_big[b]) {
+            connections.insert((a.min(b), a.max(b)));
+        }
+    }
+
+    Generated {
+        input: connections
+            .iter()
+            .map(|&(a, b)| format!("{}-{}", names[a], names[b]))
+            .join("\n"),
+        part1: None,
+        part2: None,
+    }
+}
+
+/// Lowercase letters which are unique for every index
+fn cave_letters(mut idx: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push((b'a' + (idx % 26) as u8) as char);
+        idx /= 26;
+        if idx == 0 {
+            return letters.into_iter().rev().collect();
+        }
+        // Bijective numbering, otherwise `a` and `aa` would both stand for 0
+        idx -= 1;
+    }
+}
+
+/// Transparent paper which shows a random 40x6 pattern after `folds` folds, which must be at least 2
+///
+/// The paper is unfolded from the pattern, folding along x and y at least once each.
+pub fn day13(seed: u64, folds: usize) -> Generated {
+    assert!(folds >= 2, "Day 13 needs at least 2 folds");
+    let mut rng = rng(seed);
+
+    let (mut width, mut height) = (40, 6);
+    let mut dots: Set<(usize, usize)> = (0..width)
+        .flat_map(|x| (0..height).map(move |y| (x, y)))
+        .filter(|_| rng.gen_bool(0.3))
+        .collect();
+    dots.insert((0, 0));
+    let pattern = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| if dots.contains(&(x, y)) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .join("\n");
+
+    let mut along_x = (0..folds).map(|_| rng.gen_bool(0.5)).collect_vec();
+    along_x[0] = true;
+    along_x[1] = false;
+    along_x.shuffle(&mut rng);
+
+    // Unfold from the last fold to the first one, keeping each dot, its mirror image or both
+    let mut instructions = Vec::new();
+    let mut first_fold = 0;
+    for &x in along_x.iter().rev() {
+        first_fold = dots.len();
+        let place = if x { width } else { height };
+        let mirror = |pos: usize| 2 * place - pos;
+        dots = dots
+            .into_iter()
+            .flat_map(|(x_pos, y_pos)| {
+                let mirrored = if x {
+                    (mirror(x_pos), y_pos)
+                } else {
+                    (x_pos, mirror(y_pos))
+                };
+                match rng.gen_range(0..3) {
+                    0 => vec![(x_pos, y_pos)],
+                    1 => vec![mirrored],
+                    _ => vec![(x_pos, y_pos), mirrored],
+                }
+            })
+            .collect();
+        if x {
+            width = 2 * width + 1;
+        } else {
+            height = 2 * height + 1;
+        }
+        instructions.push(format!(
+            "fold along {}={}",
+            if x { 'x' } else { 'y' },
+            place
+        ));
+    }
+    instructions.reverse();
+
+    let mut dots = dots.into_iter().collect_vec();
+    dots.shuffle(&mut rng);
+    Generated {
+        input: format!(
+            "{}\n\n{}",
+            dots.iter().map(|(x, y)| format!("{},{}", x, y)).join("\n"),
+            instructions.join("\n")
+        ),
+        part1: Some(first_fold.to_string()),
+        part2: Some(format!("\n{}\n", pattern)),
+    }
+}
+
+/// Polymer template of `length` elements, which must be at least 1, with rules for all pairs of `elements` elements
+///
+/// Only part 1 is expanded to the full polymer, as the polymer of part 2 is far too long.
+pub fn day14(seed: u64, length: usize, elements: usize) -> Generated {
+    assert!(length >= 1, "Day 14 needs a template of at least 1 element");
+    assert!(
+        (1..=26).contains(&elements),
+        "Day 14 needs 1 to 26 elements"
+    );
+    let mut rng = rng(seed);
+
+    let element = |idx: usize| (b'A' + idx as u8) as char;
+    let template = (0..length)
+        .map(|_| element(rng.gen_range(0..elements)))
+        .collect::<String>();
+    let rules: Map<(char, char), char> = (0..elements)
+        .flat_map(|a| (0..elements).map(move |b| (element(a), element(b))))
+        .map(|pair| (pair, element(rng.gen_range(0..elements))))
+        .collect();
+
+    let mut polymer: Vec<char> = template.chars().collect();
+    for _ in 0..10 {
+        let mut next = vec![polymer[0]];
+        for pair in polymer.windows(2) {
+            next.push(rules[&(pair[0], pair[1])]);
+            next.push(pair[1]);
+        }
+        polymer = next;
+    }
+    let counts = polymer.iter().counts();
+    let (least, most) = counts.values().minmax().into_option().unwrap();
+
+    Generated {
+        input: format!(
+            "{}\n\n{}",
+            template,
+            rules
+                .iter()
+                .map(|((a, b), insertion)| format!("{}{} -> {}", a, b, insertion))
+                .join("\n")
+        ),
+        part1: Some((most - least).to_string()),
+        part2: None,
+    }
+}
+
+/// BITS transmission with roughly `packets` packets
+pub fn day16(seed: u64, packets: usize) -> Generated {
+    let mut rng = rng(seed);
+
+    let mut budget = packets;
+    let packet = bits_packet(&mut rng, &mut budget, 0);
+    let mut bits = packet.bits;
+    // Pad with unset bits to complete the last hexadecimal digit
+    while bits.len() % 4 != 0 {
+        bits.push(false);
+    }
+
+    Generated {
+        input: bits
+            .chunks(4)
+            .map(|nibble| {
+                let value = nibble.iter().fold(0, |acc, &bit| (acc << 1) + bit as u32);
+                char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect(),
+        part1: Some(packet.version_sum.to_string()),
+        part2: Some(packet.value.to_string()),
+    }
+}
+
+struct BitsPacket {
+    bits: Vec<bool>,
+    version_sum: u32,
+    value: u128,
+}
+
+/// Most packets a count defined operator can hold
+const BITS_MAX_SUBPACKETS: usize = (1 << 11) - 1;
+
+fn bits_packet(rng: &mut ChaCha8Rng, budget: &mut usize, depth: usize) -> BitsPacket {
+    *budget = budget.saturating_sub(1);
+    let version = rng.gen_range(0..8);
+    // The outermost packet is always an operator, such that the transmission gets long
+    if *budget == 0 || depth >= 12 || (depth > 0 && rng.gen_bool(0.25)) {
+        return bits_literal(rng, version);
+    }
+
+    let type_ids: &[u64] = if depth == 0 {
+        &[0, 1, 2, 3]
+    } else {
+        &[0, 1, 2, 3, 5, 6, 7]
+    };
+    let mut type_id = *type_ids.choose(rng).unwrap();
+    let count = match type_id {
+        // Comparisons always have exactly two subpackets
+        5..=7 => 2,
+        // The outermost packet holds as many packets as the budget allows
+        _ if depth == 0 => BITS_MAX_SUBPACKETS,
+        _ => rng.gen_range(1..=8),
+    };
+    let mut subpackets = Vec::new();
+    while subpackets.len() < count {
+        if type_id < 5 && !subpackets.is_empty() && *budget == 0 {
+            break;
+        }
+        subpackets.push(bits_packet(rng, budget, depth + 1));
+    }
+
+    let mut values = subpackets.iter().map(|p| p.value);
+    let value = match type_id {
+        0 => values.try_fold(0_u128, |acc, v| acc.checked_add(v)),
+        1 => values.try_fold(1_u128, |acc, v| acc.checked_mul(v)),
+        2 => values.min(),
+        3 => values.max(),
+        5 => Some((subpackets[0].value > subpackets[1].value) as u128),
+        6 => Some((subpackets[0].value < subpackets[1].value) as u128),
+        _ => Some((subpackets[0].value == subpackets[1].value) as u128),
+    };
+    // The solutions cannot evaluate sums and products which overflow, so take the maximum instead
+    let value = value.unwrap_or_else(|| {
+        type_id = 3;
+        subpackets.iter().map(|p| p.value).max().unwrap()
+    });
+
+    let mut bits = Vec::new();
+    push_bits(&mut bits, version, 3);
+    push_bits(&mut bits, type_id, 3);
+    let length: usize = subpackets.iter().map(|p| p.bits.len()).sum();
+    if length < 1 << 15 && rng.gen_bool(0.5) {
+        bits.push(false);
+        push_bits(&mut bits, length as u64, 15);
+    } else {
+        bits.push(true);
+        push_bits(&mut bits, subpackets.len() as u64, 11);
+    }
+    let mut version_sum = version as u32;
+    for subpacket in subpackets {
+        bits.extend(subpacket.bits);
+        version_sum += subpacket.version_sum;
+    }
+
+    BitsPacket {
+        bits,
+        version_sum,
+        value,
+    }
+}
+
+fn bits_literal(rng: &mut ChaCha8Rng, version: u64) -> BitsPacket {
+    // Vary the magnitude, otherwise almost all literals would be huge
+    let width = rng.gen_range(1..=32);
+    let literal = rng.gen_range(0..1_u64 << width);
+
+    let mut bits = Vec::new();
+    push_bits(&mut bits, version, 3);
+    push_bits(&mut bits, 4, 3);
+    let groups = ((64 - literal.leading_zeros() + 3) / 4).max(1);
+    for group in (0..groups).rev() {
+        // Every group except the last one starts with a set bit
+        bits.push(group != 0);
+        push_bits(&mut bits, literal >> (group * 4), 4);
+    }
+
+    BitsPacket {
+        bits,
+        version_sum: version as u32,
+        value: literal as u128,
+    }
+}
+
+/// Append the lowest `width` bits of `value`, most significant first
+fn push_bits(bits: &mut Vec<bool>, value: u64, width: u32) {
+    bits.extend((0..width).rev().map(|i| (value >> i) & 1 == 1));
+}
+
+/// Homework with `numbers` reduced snailfish numbers, which must be at least 1
+///
+/// The numbers are nested as deeply as a reduced number allows most of the time.
+pub fn day18(seed: u64, numbers: usize) -> Generated {
+    assert!(numbers >= 1, "Day 18 needs at least 1 number");
+    let mut rng = rng(seed);
+
+    let input = (0..numbers)
+        .map(|_| {
+            let mut number = String::new();
+            snailfish_pair(&mut rng, 1, &mut number);
+            number
+        })
+        .join("\n");
+    Generated {
+        input,
+        part1: None,
+        part2: None,
+    }
+}
+
+fn snailfish_pair(rng: &mut ChaCha8Rng, depth: usize, out: &mut String) {
+    out.push('[');
+    for idx in 0..2 {
+        if idx == 1 {
+            out.push(',');
+        }
+        // Reduced numbers have no pair nested inside four pairs
+        if depth < 4 && rng.gen_bool(0.75) {
+            snailfish_pair(rng, depth + 1, out);
+        } else {
+            out.push(char::from_digit(rng.gen_range(0..10), 10).unwrap());
+        }
+    }
+    out.push(']');
+}
@@ -0,0 +1,73 @@
+//! Check every solver against the expected answers in `answers.toml`
+
+use advent_of_code_2021::*;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Manifest {
+    answer: Vec<Answer>,
+}
+
+#[derive(Deserialize)]
+struct Answer {
+    year: u32,
+    day: u32,
+    part: u32,
+    variant: Option<String>,
+    answer: String,
+}
+
+#[test]
+fn test_answers() {
+    let manifest: Manifest = toml::from_str(include_str!("../answers.toml")).unwrap();
+    let solvers = solvers();
+    let mut failures = Vec::new();
+
+    for solver in &solvers {
+        let name = format!("{} {}", solver.year, solver.name());
+        let expected = match manifest.answer.iter().find(|answer| {
+            answer.year == solver.year
+                && answer.day == solver.day
+                && answer.part == solver.part
+                && answer.variant.as_deref() == solver.variant
+        }) {
+            Some(expected) => expected,
+            None => {
+                failures.push(format!("{}: missing from answers.toml", name));
+                continue;
+            }
+        };
+
+        let input = std::fs::read_to_string(solver.input_path()).unwrap();
+        match solver.solve(&input) {
+            Ok(answer) => {
+                let answer = answer.to_string();
+                if answer.trim_matches('\n') != expected.answer.trim_matches('\n') {
+                    failures.push(format!(
+                        "{}: expected {}, but got {}",
+                        name, expected.answer, answer
+                    ));
+                }
+            }
+            Err(err) => failures.push(format!("{}: failed with {}", name, err)),
+        }
+    }
+
+    // Answers without a solver are most likely typos, unless the whole day is disabled
+    for answer in &manifest.answer {
+        let variant = answer.variant.as_deref();
+        let same_day = |solver: &Solver| solver.year == answer.year && solver.day == answer.day;
+        let day_enabled = solvers.iter().any(same_day);
+        let has_solver = solvers.iter().any(|solver| {
+            same_day(solver) && solver.part == answer.part && solver.variant == variant
+        });
+        if day_enabled && !has_solver {
+            failures.push(format!(
+                "answers.toml: no solver for year {}, day {}, part {} and variant {:?}",
+                answer.year, answer.day, answer.part, variant
+            ));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,167 @@
+//! Check that the solvers accept the generated inputs and agree with the generated answers
+
+use advent_of_code_2021::y2021::generate;
+
+/// Run all solvers of the day of 2021, which must succeed, and compare them with the generated answers if there are any
+// Unused if only days without a generator are enabled
+#[allow(dead_code)]
+fn check(generated: &generate::Generated, day: u32) {
+    use advent_of_code_2021::solvers;
+
+    for solver in solvers()
+        .iter()
+        .filter(|solver| solver.year == 2021 && solver.day == day)
+    {
+        let expected = match solver.part {
+            1 => &generated.part1,
+            _ => &generated.part2,
+        };
+        let answer = solver
+            .solve(&generated.input)
+            .unwrap_or_else(|err| panic!("{}\nfor input:\n{}", err, generated.input));
+        if let Some(expected) = expected {
+            assert_eq!(
+                expected,
+                &answer.to_string(),
+                "for input:\n{}",
+                generated.input
+            );
+        }
+    }
+}
+
+#[test]
+fn test_deterministic() {
+    let generators: [fn(u64) -> generate::Generated; 14] = [
+        |seed| generate::day01(seed, 50),
+        |seed| generate::day02(seed, 50),
+        |seed| generate::day03(seed, 50, 8),
+        |seed| generate::day04(seed, 5),
+        |seed| generate::day05(seed, 50, 99),
+        |seed| generate::day06(seed, 50),
+        |seed| generate::day07(seed, 50, 99),
+        |seed| generate::day08(seed, 50),
+        |seed| generate::day10(seed, 50),
+        |seed| generate::day12(seed, 8),
+        |seed| generate::day13(seed, 4),
+        |seed| generate::day14(seed, 10, 4),
+        |seed| generate::day16(seed, 50),
+        |seed| generate::day18(seed, 10),
+    ];
+    for generator in generators {
+        assert_eq!(generator(1), generator(1));
+        assert_ne!(generator(1), generator(2));
+    }
+}
+
+#[cfg(feature = "y2021-day01")]
+#[test]
+fn test_day01() {
+    for seed in 0..10 {
+        check(&generate::day01(seed, 1000), 1);
+    }
+}
+
+#[cfg(feature = "y2021-day02")]
+#[test]
+fn test_day02() {
+    for seed in 0..10 {
+        check(&generate::day02(seed, 1000), 2);
+    }
+}
+
+#[cfg(feature = "y2021-day03")]
+#[test]
+fn test_day03() {
+    for seed in 0..10 {
+        check(&generate::day03(seed, 1000, 12), 3);
+    }
+}
+
+#[cfg(feature = "y2021-day04")]
+#[test]
+fn test_day04() {
+    for seed in 0..10 {
+        check(&generate::day04(seed, 100), 4);
+    }
+}
+
+#[cfg(feature = "y2021-day05")]
+#[test]
+fn test_day05() {
+    for seed in 0..10 {
+        check(&generate::day05(seed, 100, 999), 5);
+    }
+}
+
+#[cfg(feature = "y2021-day06")]
+#[test]
+fn test_day06() {
+    for seed in 0..10 {
+        check(&generate::day06(seed, 300), 6);
+    }
+}
+
+#[cfg(feature = "y2021-day07")]
+#[test]
+fn test_day07() {
+    for seed in 0..10 {
+        check(&generate::day07(seed, 1000, 2000), 7);
+    }
+}
+
+#[cfg(feature = "y2021-day08")]
+#[test]
+fn test_day08() {
+    for seed in 0..10 {
+        check(&generate::day08(seed, 200), 8);
+    }
+}
+
+#[cfg(feature = "y2021-day10")]
+#[test]
+fn test_day10() {
+    for seed in 0..10 {
+        check(&generate::day10(seed, 99), 10);
+    }
+}
+
+#[cfg(feature = "y2021-day12")]
+#[test]
+fn test_day12() {
+    for seed in 0..10 {
+        check(&generate::day12(seed, 8), 12);
+    }
+}
+
+#[cfg(feature = "y2021-day13")]
+#[test]
+fn test_day13() {
+    for seed in 0..10 {
+        check(&generate::day13(seed, 12), 13);
+    }
+}
+
+#[cfg(feature = "y2021-day14")]
+#[test]
+fn test_day14() {
+    for seed in 0..10 {
+        check(&generate::day14(seed, 20, 10), 14);
+    }
+}
+
+#[cfg(feature = "y2021-day16")]
+#[test]
+fn test_day16() {
+    for seed in 0..10 {
+        check(&generate::day16(seed, 1000), 16);
+    }
+}
+
+#[cfg(feature = "y2021-day18")]
+#[test]
+fn test_day18() {
+    for seed in 0..10 {
+        check(&generate::day18(seed, 10), 18);
+    }
+}
@@ -0,0 +1,104 @@
+//! Check the input fetcher against a local stand-in for the server
+
+use advent_of_code_2021::inputs::{FetchError, Fetcher};
+use std::io::{BufRead as _, BufReader, Write as _};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread::JoinHandle;
+
+/// Serve a single request with the raw `response` and return the request line and headers
+fn serve_once(response: &'static str) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let request: Vec<String> = BufReader::new(&stream)
+            .lines()
+            .map(Result::unwrap)
+            .take_while(|line| !line.is_empty())
+            .collect();
+        stream.write_all(response.as_bytes()).unwrap();
+        request
+    });
+    (base_url, server)
+}
+
+/// Empty cache directory for one test
+fn input_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-inputs-{}-{}", std::process::id(), test));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_fetch() {
+    let (base_url, server) =
+        serve_once("HTTP/1.1 200 OK\r\nContent-Length: 8\r\nConnection: close\r\n\r\n199\n200\n");
+    let dir = input_dir("fetch");
+    let fetcher = Fetcher::new(base_url, Some("secret".to_string()), &dir);
+
+    let path = fetcher.fetch(2021, 1).unwrap();
+    assert_eq!(dir.join("2021").join("day1.txt"), path);
+    assert_eq!("199\n200\n", std::fs::read_to_string(&path).unwrap());
+    let request = server.join().unwrap();
+    assert_eq!("GET /2021/day/1/input HTTP/1.1", request[0]);
+    assert!(request
+        .iter()
+        .any(|header| header.eq_ignore_ascii_case("cookie: session=secret")));
+
+    // The server is gone, so this only succeeds from the cache
+    assert_eq!(path, fetcher.fetch(2021, 1).unwrap());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_missing_token() {
+    let dir = input_dir("missing-token");
+    let fetcher = Fetcher::new("http://127.0.0.1:1", None, &dir);
+    assert!(matches!(
+        fetcher.fetch(2021, 1),
+        Err(FetchError::MissingToken)
+    ));
+
+    // Cached inputs need no token
+    std::fs::create_dir_all(dir.join("2021")).unwrap();
+    std::fs::write(dir.join("2021").join("day1.txt"), "199\n").unwrap();
+    assert!(fetcher.fetch(2021, 1).is_ok());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_rate_limited() {
+    let (base_url, server) = serve_once(
+        "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 120\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+    );
+    let dir = input_dir("rate-limited");
+    let fetcher = Fetcher::new(base_url, Some("secret".to_string()), &dir);
+
+    let err = fetcher.fetch(2021, 2).unwrap_err();
+    server.join().unwrap();
+    assert_eq!(
+        "Rate limited by the server, retry in 120 seconds",
+        err.to_string()
+    );
+    assert!(!fetcher.is_cached(2021, 2));
+}
+
+#[test]
+fn test_status() {
+    let (base_url, server) = serve_once(
+        "HTTP/1.1 400 Bad Request\r\nContent-Length: 40\r\nConnection: close\r\n\r\nPlease log in to get your puzzle input.\n",
+    );
+    let dir = input_dir("status");
+    let fetcher = Fetcher::new(base_url, Some("expired".to_string()), &dir);
+
+    match fetcher.fetch(2021, 3) {
+        Err(FetchError::Status { status, body, .. }) => {
+            assert_eq!(400, status);
+            assert_eq!("Please log in to get your puzzle input.", body);
+        }
+        result => panic!("Expected status 400, got {:?}", result),
+    }
+    server.join().unwrap();
+    assert!(!fetcher.is_cached(2021, 3));
+}